@@ -0,0 +1,75 @@
+/* Hashes of the sources of the solvers, which version the answers of the cache, see src/cache.rs.
+ *
+ * The hash of a crate covers all the files of its `src`, and the ones of its path dependencies such as
+ * `aoc_common`, so that a change in a helper of a solver also invalidates its cached answers. It is
+ * given to the registry as the variable `AOC_SOURCE_HASH_<crate>`.
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Paths of the crates given as `path` dependencies in the manifest of the crate at `dir`.
+fn path_dependencies(dir: &Path) -> Vec<PathBuf> {
+    let manifest = dir.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest.display());
+
+    let text = fs::read_to_string(&manifest).unwrap_or_else(|e| panic!("Could not read '{}': {e}", manifest.display()));
+    text.lines()
+        .filter_map(|line| line.split("path = \"").nth(1)?.split('"').next())
+        .map(|path| fs::canonicalize(dir.join(path)).unwrap_or_else(|e| panic!("Could not find '{path}': {e}")))
+        .collect()
+}
+
+/// Files under `dir`, recursively.
+fn files(dir: &Path, result: &mut Vec<PathBuf>) {
+    let entries = fs::read_dir(dir).unwrap_or_else(|e| panic!("Could not read '{}': {e}", dir.display()));
+    for path in entries.map(|entry| entry.unwrap().path()) {
+        if path.is_dir() {
+            files(&path, result);
+        }
+        else {
+            result.push(path);
+        }
+    }
+}
+
+/// Sources of the crate at `dir` and of its path dependencies, in a stable order.
+fn sources(dir: &Path, result: &mut Vec<PathBuf>) {
+    let src = dir.join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+    files(&src, result);
+
+    for dependency in path_dependencies(dir) {
+        sources(&dependency, result);
+    }
+}
+
+/// 64-bit FNV-1a, the same as `aoc_common::input::hash`.
+fn hash(bytes: impl IntoIterator<Item = u8>, mut hash: u64) -> u64 {
+    for byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+fn main() {
+    let workspace = fs::canonicalize("..").unwrap();
+
+    for krate in path_dependencies(Path::new(".")) {
+        let mut paths = Vec::new();
+        sources(&krate, &mut paths);
+        paths.sort();
+        paths.dedup();
+
+        /* The names of the files are hashed as well, relative to the workspace */
+        let mut source_hash = 0xcbf29ce484222325;
+        for path in paths {
+            let name = path.strip_prefix(&workspace).unwrap_or(&path).to_string_lossy().replace('\\', "/");
+            let text = fs::read(&path).unwrap_or_else(|e| panic!("Could not read '{}': {e}", path.display()));
+            source_hash = hash(name.bytes().chain([0]).chain(text).chain([0]), source_hash);
+        }
+
+        let name = krate.file_name().unwrap().to_string_lossy();
+        println!("cargo:rustc-env=AOC_SOURCE_HASH_{name}={source_hash:016x}");
+    }
+}
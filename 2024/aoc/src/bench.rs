@@ -0,0 +1,475 @@
+/* `aoc bench`: benchmark of the solutions, checked against time budgets and a baseline.
+ *
+ * The budgets and the regression threshold are read from a config file of `key = value` lines:
+ *   default = 1s                 budget of every part
+ *   2024/6 = 5s                  budget of the parts of a day
+ *   2024/6/2 = 1m                budget of a part
+ *   regression_threshold = 10%   slow down above which a part regresses
+ *
+ * `--baseline` appends the results to the history file. The other runs are compared with the
+ * last baseline of each part on the same input, e.g. the example or the personal input: a part
+ * regresses when it is slower by more than the threshold and Welch's t-test says that the slow
+ * down is significant.
+ */
+use crate::cli;
+use crate::registry::Solution;
+use aoc_common::args::{parse_duration, Args};
+use aoc_common::input;
+use aoc_common::runner::{self, Outcome, RunOptions};
+use std::collections::HashMap;
+use std::fs::{read_to_string, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+pub const USAGE: &str = "aoc bench [--day <N>] [--part <P>] [--tag <TAG>]... [--year <Y>] [--input <FILE|-> | --test] [--runs <N>] [--baseline]
+                  [--config <FILE>] [--history <FILE>] [--timeout <DURATION>]";
+
+const DEFAULT_RUNS: usize = 10;
+const DEFAULT_THRESHOLD: f64 = 0.1;
+const CONFIG_FILE: &str = "../bench.conf";
+pub const HISTORY_FILE: &str = "../bench_history.txt";
+
+/// Statistics of the run times of a part, in seconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub mean: f64,
+    pub stddev: f64,
+    pub median: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut seconds: Vec<f64> = samples.iter().map(|d| d.as_secs_f64()).collect();
+        seconds.sort_by(|a, b| a.total_cmp(b));
+
+        let runs = seconds.len();
+        let mean = seconds.iter().sum::<f64>() / runs.max(1) as f64;
+        let variance = match runs {
+            0 | 1 => 0.0,
+            _ => seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64,
+        };
+        let median = match runs {
+            0 => 0.0,
+            _ if runs % 2 == 1 => seconds[runs / 2],
+            _ => (seconds[runs / 2 - 1] + seconds[runs / 2]) / 2.0,
+        };
+
+        Self { runs, mean, stddev: variance.sqrt(), median }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    default_budget: Option<Duration>,
+    /// Budgets by year, day and part, the part being `None` for the budget of a day
+    budgets: HashMap<(u16, u8, Option<u8>), Duration>,
+    pub regression_threshold: f64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { default_budget: None, budgets: HashMap::new(), regression_threshold: DEFAULT_THRESHOLD }
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+
+        for (idx, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let error = |message: &str| format!("line {}: {message}", idx + 1);
+            let Some((key, value)) = line.split_once('=').map(|(k, v)| (k.trim(), v.trim())) else {
+                return Err(error("expected 'key = value'"));
+            };
+
+            if key == "regression_threshold" {
+                let percent: f64 = value.trim_end_matches('%').trim().parse().map_err(|_| error("invalid percentage"))?;
+                config.regression_threshold = percent / 100.0;
+                continue;
+            }
+
+            let budget = parse_duration(value).ok_or_else(|| error("invalid duration"))?;
+            if key == "default" {
+                config.default_budget = Some(budget);
+                continue;
+            }
+
+            let fields: Vec<&str> = key.split('/').collect();
+            let key_error = || error("expected 'default', 'regression_threshold' or '<year>/<day>[/<part>]'");
+            let year: u16 = fields[0].parse().map_err(|_| key_error())?;
+            let numbers: Vec<u8> = fields[1..].iter().map(|f| f.parse().map_err(|_| key_error())).collect::<Result<_, _>>()?;
+            match numbers[..] {
+                [day] => config.budgets.insert((year, day, None), budget),
+                [day, part] => config.budgets.insert((year, day, Some(part)), budget),
+                _ => return Err(key_error()),
+            };
+        }
+
+        Ok(config)
+    }
+
+    /// Reads the config file, a missing file giving no budget.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.is_file() {
+            return Ok(Config::default());
+        }
+
+        let text = read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?;
+        Self::parse(&text).map_err(|e| format!("Invalid config '{}': {e}", path.display()))
+    }
+
+    /// Budget of a part, from the most specific entry.
+    pub fn budget(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.budgets.get(&(year, day, Some(part)))
+                    .or(self.budgets.get(&(year, day, None)))
+                    .copied()
+                    .or(self.default_budget)
+    }
+}
+
+/// Result of a part stored in the history file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Hash of the input, see `input::hash`. `None` for the lines written before it was recorded,
+    /// which are never a baseline as their input is unknown
+    pub input_hash: Option<String>,
+    pub stats: Stats,
+}
+
+impl HistoryEntry {
+    fn to_line(&self) -> String {
+        let input = self.input_hash.as_ref().map(|h| format!(" input={h}")).unwrap_or_default();
+        format!("{} {} {} {}{input} runs={} mean={} stddev={} median={}", self.timestamp, self.year, self.day, self.part,
+                self.stats.runs, self.stats.mean, self.stats.stddev, self.stats.median)
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        let input_hash = fields.get(4).and_then(|f| f.strip_prefix("input=")).map(str::to_string);
+        if input_hash.is_some() {
+            fields.remove(4);
+        }
+        if fields.len() != 8 {
+            return None;
+        }
+
+        /* The times become durations, which cannot be negative */
+        let value = |idx: usize, key: &str| fields[idx].strip_prefix(key)?.strip_prefix('=')?.parse::<f64>().ok()
+                                                      .filter(|v| v.is_finite() && *v >= 0.0);
+        Some(Self {
+            timestamp: fields[0].parse().ok()?,
+            year: fields[1].parse().ok()?,
+            day: fields[2].parse().ok()?,
+            part: fields[3].parse().ok()?,
+            input_hash,
+            stats: Stats {
+                runs: value(4, "runs")? as usize,
+                mean: value(5, "mean")?,
+                stddev: value(6, "stddev")?,
+                median: value(7, "median")?,
+            },
+        })
+    }
+}
+
+pub fn load_history(path: &Path) -> Result<Vec<HistoryEntry>, String> {
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+
+    let text = read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?;
+    text.lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty() && !l.starts_with('#'))
+        .map(|(idx, l)| HistoryEntry::from_line(l).ok_or(format!("Invalid history '{}': line {}", path.display(), idx + 1)))
+        .collect()
+}
+
+pub fn append_history(path: &Path, entries: &[HistoryEntry]) -> Result<(), String> {
+    let error = |e: std::io::Error| format!("Could not write '{}': {e}", path.display());
+    let is_new = !path.is_file();
+    let mut file = OpenOptions::new().create(true).append(true).open(path).map_err(error)?;
+
+    if is_new {
+        writeln!(file, "# Baselines of `aoc bench`: timestamp year day part input=<hash>, then times in seconds").map_err(error)?;
+    }
+    for entry in entries {
+        writeln!(file, "{}", entry.to_line()).map_err(error)?;
+    }
+
+    Ok(())
+}
+
+/// Last baseline of a part on the input of hash `input_hash`
+pub fn baseline<'a>(history: &'a [HistoryEntry], year: u16, day: u8, part: u8, input_hash: &str) -> Option<&'a Stats> {
+    history.iter()
+           .rev()
+           .find(|e| e.year == year && e.day == day && e.part == part && e.input_hash.as_deref() == Some(input_hash))
+           .map(|e| &e.stats)
+}
+
+/// Change of the mean run time from the baseline, in percent. None when the baseline took no time.
+fn change_percent(baseline: &Stats, current: &Stats) -> Option<f64> {
+    (baseline.mean > 0.0).then(|| (current.mean / baseline.mean - 1.0) * 100.0)
+}
+
+/// One-sided critical value of the Student's t distribution at 95%.
+fn t_critical(degrees_of_freedom: f64) -> f64 {
+    const TABLE: [f64; 30] = [
+        6.314, 2.920, 2.353, 2.132, 2.015, 1.943, 1.895, 1.860, 1.833, 1.812,
+        1.796, 1.782, 1.771, 1.761, 1.753, 1.746, 1.740, 1.734, 1.729, 1.725,
+        1.721, 1.717, 1.714, 1.711, 1.708, 1.706, 1.703, 1.701, 1.699, 1.697,
+    ];
+
+    match degrees_of_freedom.floor() as usize {
+        0 => TABLE[0],
+        df if df <= TABLE.len() => TABLE[df - 1],
+        _ => 1.645,
+    }
+}
+
+/// Whether `current` is slower than `baseline` by more than `threshold`, and significantly so
+/// according to Welch's t-test.
+pub fn is_regression(baseline: &Stats, current: &Stats, threshold: f64) -> bool {
+    if current.mean <= baseline.mean * (1.0 + threshold) {
+        return false;
+    }
+
+    let base_var = baseline.stddev.powi(2) / baseline.runs.max(1) as f64;
+    let current_var = current.stddev.powi(2) / current.runs.max(1) as f64;
+    let standard_error = (base_var + current_var).sqrt();
+    if standard_error == 0.0 || baseline.runs < 2 || current.runs < 2 {
+        /* No variance to test, the threshold decides */
+        return true;
+    }
+
+    let t = (current.mean - baseline.mean) / standard_error;
+    let degrees_of_freedom = (base_var + current_var).powi(2)
+                             / (base_var.powi(2) / (baseline.runs - 1) as f64 + current_var.powi(2) / (current.runs - 1) as f64);
+
+    t > t_critical(degrees_of_freedom)
+}
+
+/// Runs a part `runs` times after a warm-up run, returning its run times.
+fn bench_part(solution: &Solution, input: &str, runs: usize, options: &RunOptions) -> Result<Vec<Duration>, String> {
+    let mut samples = Vec::with_capacity(runs);
+
+    for run in 0..=runs {
+        let report = runner::run_part(solution.solve, input, options);
+        if !matches!(report.outcome, Outcome::Solved(_)) {
+            return Err(report.outcome.to_string());
+        }
+        /* The first run warms up the caches */
+        if run > 0 {
+            samples.push(report.elapsed);
+        }
+    }
+
+    Ok(samples)
+}
+
+/// Returns whether every part was benchmarked, within its budget and without regression.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let solutions = cli::selected_solutions(args, false)?;
+    cli::check_piped_input(&solutions, args)?;
+    let runs = args.parsed_value("--runs")?.unwrap_or(DEFAULT_RUNS).max(1);
+    let config = Config::load(Path::new(args.value("--config").unwrap_or(CONFIG_FILE)))?;
+    let history_path = Path::new(args.value("--history").unwrap_or(HISTORY_FILE));
+    let history = load_history(history_path)?;
+    let options = RunOptions { timeout: args.duration("--timeout")?, ..RunOptions::default() };
+
+    let timestamp = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default();
+    let mut results = Vec::new();
+    let mut all_ok = true;
+
+    for solution in solutions {
+        let label = cli::label(solution);
+        let input = match cli::input_source(solution, args).read() {
+            Ok(i) => i,
+            Err(e) => {
+                println!("{label}: skipped: {e}");
+                all_ok = false;
+                continue;
+            },
+        };
+
+        let samples = match bench_part(solution, &input, runs, &options) {
+            Ok(s) => s,
+            Err(e) => {
+                println!("{label}: {e}");
+                all_ok = false;
+                continue;
+            },
+        };
+        let stats = Stats::from_samples(&samples);
+        let input_hash = input::hash(&input);
+        let mut verdicts = Vec::new();
+
+        if let Some(budget) = config.budget(solution.year, solution.day, solution.part) {
+            let within = stats.median <= budget.as_secs_f64();
+            verdicts.push(format!("budget {budget:?} {}", if within { "ok" } else { "EXCEEDED" }));
+            all_ok &= within;
+        }
+        if let Some(base) = baseline(&history, solution.year, solution.day, solution.part, &input_hash) {
+            let regressed = is_regression(base, &stats, config.regression_threshold);
+            let change = change_percent(base, &stats).map(|c| format!(" {c:+.1}%")).unwrap_or_default();
+            verdicts.push(format!("baseline {:?}{change} {}", Duration::from_secs_f64(base.mean),
+                                  if regressed { "REGRESSED" } else { "ok" }));
+            all_ok &= !regressed;
+        }
+
+        println!("{label}: median {:?}, mean {:?} ± {:?} ({runs} runs){}", Duration::from_secs_f64(stats.median),
+                 Duration::from_secs_f64(stats.mean), Duration::from_secs_f64(stats.stddev),
+                 verdicts.iter().map(|v| format!(", {v}")).collect::<String>());
+
+        results.push(HistoryEntry {
+            timestamp, year: solution.year, day: solution.day, part: solution.part, input_hash: Some(input_hash), stats,
+        });
+    }
+
+    if args.flag("--baseline") {
+        append_history(history_path, &results)?;
+        println!("Baseline of {} part(s) saved to '{}'", results.len(), history_path.display());
+    }
+
+    Ok(all_ok)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(runs: usize, mean: f64, stddev: f64) -> Stats {
+        Stats { runs, mean, stddev, median: mean }
+    }
+
+    fn stats_with_median(runs: usize, mean: f64, stddev: f64, median: f64) -> Stats {
+        Stats { runs, mean, stddev, median }
+    }
+
+    #[test]
+    fn stats_of_samples() {
+        let stats = Stats::from_samples(&[4, 1, 3, 2].map(Duration::from_millis));
+        assert_eq!((stats.runs, stats.median), (4, 0.0025));
+        assert!((stats.mean - 0.0025).abs() < 1e-12);
+        assert!((stats.stddev - (5.0f64 / 3.0).sqrt() / 1000.0).abs() < 1e-12);
+
+        assert_eq!(Stats::from_samples(&[Duration::from_secs(2)]), stats_with_median(1, 2.0, 0.0, 2.0));
+        assert_eq!(Stats::from_samples(&[]), stats_with_median(0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn regressions() {
+        let baseline = stats(10, 1.0, 0.01);
+
+        /* Within the threshold, however significant */
+        assert!(!is_regression(&baseline, &stats(10, 1.09, 0.01), 0.1));
+        assert!(is_regression(&baseline, &stats(10, 1.2, 0.01), 0.1));
+        assert!(!is_regression(&baseline, &stats(10, 0.5, 0.01), 0.1));
+
+        /* Above the threshold, but within the noise */
+        assert!(!is_regression(&stats(3, 1.0, 0.5), &stats(3, 1.3, 0.5), 0.1));
+        assert!(is_regression(&stats(30, 1.0, 0.5), &stats(30, 1.3, 0.5), 0.1));
+    }
+
+    #[test]
+    fn regressions_without_variance() {
+        /* The threshold decides alone */
+        assert!(is_regression(&stats(10, 1.0, 0.0), &stats(10, 1.2, 0.0), 0.1));
+        assert!(!is_regression(&stats(10, 1.0, 0.0), &stats(10, 1.05, 0.0), 0.1));
+        assert!(is_regression(&stats(1, 1.0, 0.0), &stats(10, 1.2, 0.5), 0.1));
+        assert!(is_regression(&stats(10, 1.0, 0.5), &stats(1, 1.2, 0.0), 0.1));
+    }
+
+    #[test]
+    fn t_critical_values() {
+        assert_eq!(t_critical(0.3), 6.314);
+        assert_eq!(t_critical(1.9), 6.314);
+        assert_eq!(t_critical(2.0), 2.920);
+        assert_eq!(t_critical(30.5), 1.697);
+        assert_eq!(t_critical(1000.0), 1.645);
+    }
+
+    #[test]
+    fn config() {
+        let config = Config::parse("# budgets\ndefault = 1s\n2024/6 = 5s  # slow day\n2024/6/2 = 1m\n\nregression_threshold = 25%\n").unwrap();
+
+        assert_eq!(config.budget(2024, 6, 2), Some(Duration::from_secs(60)));
+        assert_eq!(config.budget(2024, 6, 1), Some(Duration::from_secs(5)));
+        assert_eq!(config.budget(2024, 7, 1), Some(Duration::from_secs(1)));
+        assert_eq!(config.regression_threshold, 0.25);
+
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(Config::default().budget(2024, 1, 1), None);
+    }
+
+    #[test]
+    fn config_errors() {
+        let key_error = "expected 'default', 'regression_threshold' or '<year>/<day>[/<part>]'";
+
+        assert_eq!(Config::parse("\ndefault 1s"), Err("line 2: expected 'key = value'".to_string()));
+        assert_eq!(Config::parse("default = fast"), Err("line 1: invalid duration".to_string()));
+        assert_eq!(Config::parse("regression_threshold = x%"), Err("line 1: invalid percentage".to_string()));
+        for key in ["2024", "2024/6/2/1", "day6", "2024/x"] {
+            assert_eq!(Config::parse(&format!("{key} = 1s")), Err(format!("line 1: {key_error}")), "{key}");
+        }
+    }
+
+    #[test]
+    fn history() {
+        let entry = HistoryEntry {
+            timestamp: 1733029200, year: 2024, day: 6, part: 2, input_hash: Some(input::hash("...#\n")),
+            stats: stats_with_median(10, 0.125, 0.003, 0.12),
+        };
+        let line = entry.to_line();
+
+        assert_eq!(line, "1733029200 2024 6 2 input=effb12f18da3484c runs=10 mean=0.125 stddev=0.003 median=0.12");
+        assert_eq!(HistoryEntry::from_line(&line), Some(entry.clone()));
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 runs=10 mean=0.125 stddev=0.003 median=0.12"),
+                   Some(HistoryEntry { input_hash: None, ..entry.clone() }));
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 runs=10 mean=0.125 stddev=0.003"), None);
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 input=x runs=10 mean=0.125 stddev=0.003"), None);
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 runs=10 median=0.125 stddev=0.003 mean=0.12"), None);
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 runs=10 mean=-1 stddev=0.003 median=0.12"), None);
+        assert_eq!(HistoryEntry::from_line("1733029200 2024 6 2 runs=10 mean=NaN stddev=0.003 median=0.12"), None);
+
+        let hash = entry.input_hash.clone().unwrap();
+        let older = HistoryEntry { timestamp: 1, stats: stats(5, 1.0, 0.0), ..entry.clone() };
+        let other = HistoryEntry { part: 1, ..older.clone() };
+        let history = [older, entry.clone(), other];
+        assert_eq!(baseline(&history, 2024, 6, 2, &hash), Some(&entry.stats));
+        assert_eq!(baseline(&history, 2024, 6, 1, &hash).map(|s| s.mean), Some(1.0));
+        assert_eq!(baseline(&history, 2024, 7, 1, &hash), None);
+    }
+
+    #[test]
+    fn baselines_of_the_same_input() {
+        let entry = |timestamp, input_hash: Option<&str>, mean| HistoryEntry {
+            timestamp, year: 2024, day: 6, part: 1, input_hash: input_hash.map(str::to_string), stats: stats(10, mean, 0.0),
+        };
+        let history = [entry(1, Some("personal"), 1.0), entry(2, Some("example"), 0.001), entry(3, None, 5.0)];
+
+        assert_eq!(baseline(&history, 2024, 6, 1, "personal").map(|s| s.mean), Some(1.0));
+        assert_eq!(baseline(&history, 2024, 6, 1, "example").map(|s| s.mean), Some(0.001));
+        assert_eq!(baseline(&history, 2024, 6, 1, "custom"), None);
+    }
+
+    #[test]
+    fn changes() {
+        assert_eq!(change_percent(&stats(10, 2.0, 0.0), &stats(10, 3.0, 0.0)), Some(50.0));
+        assert_eq!(change_percent(&stats(10, 2.0, 0.0), &stats(10, 1.0, 0.0)), Some(-50.0));
+        assert_eq!(change_percent(&stats(10, 0.0, 0.0), &stats(10, 1.0, 0.0)), None);
+    }
+}
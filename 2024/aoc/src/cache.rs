@@ -0,0 +1,228 @@
+/* Cache of the answers found by `aoc run`, so that the parts whose input and solver did not change
+ * are not solved again.
+ *
+ * Each line of the cache file is `year day part input_hash source_hash kind answer`, the answer
+ * being escaped to fit on the line and read back as the variant `kind`, see `Answer::kind`. An
+ * answer is only reused when both hashes match. The source hash covers all the sources of the
+ * crate of the solver and of its dependencies in the workspace, such as `aoc_common`, see
+ * `build.rs`: only a change of the toolchain or of an external crate needs `aoc run --no-cache`.
+ * A cache file written in another format is dropped.
+ */
+use crate::registry::Solution;
+use aoc_common::answer::Answer;
+use aoc_common::input;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const CACHE_FILE: &str = "../answer_cache.txt";
+
+/// First line of the cache file, which names its format.
+const HEADER: &str = "# Answers of `aoc run`: year day part input_hash source_hash kind answer";
+
+/// Year, day, part and hash of the input of an answer.
+type Key = (u16, u8, u8, String);
+
+#[derive(Clone, Debug)]
+struct Entry {
+    source_hash: String,
+    answer: Answer,
+}
+
+#[derive(Debug)]
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: BTreeMap<Key, Entry>,
+    modified: bool,
+}
+
+impl AnswerCache {
+    /// Loads the cache file, which may not exist yet.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut cache = Self { path: path.to_path_buf(), entries: BTreeMap::new(), modified: false };
+        if !path.is_file() {
+            return Ok(cache);
+        }
+
+        let text = fs::read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?;
+        /* The answers of another format are solved again, and the file rewritten */
+        if text.lines().next() != Some(HEADER) {
+            cache.modified = true;
+            return Ok(cache);
+        }
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, entry) = parse_line(line).ok_or(format!("Invalid answer cache '{}': line {}", path.display(), idx + 1))?;
+            cache.entries.insert(key, entry);
+        }
+
+        Ok(cache)
+    }
+
+    /// Answer of `solution` for `input`, if it was found by the same version of the solver.
+    pub fn get(&self, solution: &Solution, input: &str) -> Option<Answer> {
+        self.entries
+            .get(&key(solution, input))
+            .filter(|e| e.source_hash == solution.source_hash)
+            .map(|e| e.answer.clone())
+    }
+
+    pub fn insert(&mut self, solution: &Solution, input: &str, answer: &Answer) {
+        let entry = Entry { source_hash: solution.source_hash.to_string(), answer: answer.clone() };
+        self.entries.insert(key(solution, input), entry);
+        self.modified = true;
+    }
+
+    /// Writes the cache file, if answers were added.
+    pub fn save(&self) -> Result<(), String> {
+        if !self.modified {
+            return Ok(());
+        }
+
+        let mut text = format!("{HEADER}\n");
+        for ((year, day, part, input_hash), entry) in &self.entries {
+            text += &format!("{year} {day} {part} {input_hash} {} {} {}\n", entry.source_hash, entry.answer.kind(),
+                             escape(&entry.answer.to_string()));
+        }
+
+        fs::write(&self.path, text).map_err(|e| format!("Could not write '{}': {e}", self.path.display()))
+    }
+}
+
+fn key(solution: &Solution, input: &str) -> Key {
+    (solution.year, solution.day, solution.part, input::hash(input))
+}
+
+fn parse_line(line: &str) -> Option<(Key, Entry)> {
+    let mut fields = line.splitn(7, ' ');
+    let mut next = || fields.next();
+
+    let key = (next()?.parse().ok()?, next()?.parse().ok()?, next()?.parse().ok()?, next()?.to_string());
+    let source_hash = next()?.to_string();
+    let kind = next()?;
+    let entry = Entry { source_hash, answer: Answer::from_kind(kind, &unescape(next()?)?)? };
+    Some((key, entry))
+}
+
+/// Writes the answer on a single line, the multi-line answers being drawn in a grid.
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n").replace('\r', "\\r").replace('\t', "\\t")
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => result.push('\n'),
+                'r' => result.push('\r'),
+                't' => result.push('\t'),
+                '\\' => result.push('\\'),
+                _ => return None,
+            },
+            c => result.push(c),
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("aoc_cache_{}_{name}.txt", std::process::id()))
+    }
+
+    #[test]
+    fn escapes_round_trip() {
+        for text in ["41", "#..#\n#..#\n####", "a\\nb", "\\", "tab\there", "crlf\r\n", "ends with \\"] {
+            let escaped = escape(text);
+            assert!(!escaped.contains(['\n', '\r', '\t']), "{escaped}");
+            assert_eq!(unescape(&escaped).as_deref(), Some(text));
+        }
+
+        assert_eq!(escape("#.\n\\n"), "#.\\n\\\\n");
+        assert_eq!(unescape("unknown \\x"), None);
+        assert_eq!(unescape("trailing \\"), None);
+    }
+
+    #[test]
+    fn lines() {
+        let (key, entry) = parse_line("2024 6 1 00ff 12ab u64 41").unwrap();
+        assert_eq!(key, (2024, 6, 1, "00ff".to_string()));
+        assert_eq!((entry.source_hash.as_str(), entry.answer.kind(), entry.answer.to_string()), ("12ab", "u64", "41".to_string()));
+
+        let (_, entry) = parse_line("2024 4 2 00ff 12ab text #..#\\n#\\t.#").unwrap();
+        assert_eq!(entry.answer.to_string(), "#..#\n#\t.#");
+        let (_, entry) = parse_line("2024 4 2 00ff 12ab str two words").unwrap();
+        assert_eq!(entry.answer.to_string(), "two words");
+
+        for line in ["2024 6 1 00ff 12ab 41", "2024 6 1 00ff 12ab u64 -1", "2024 6 1 00ff 12ab u64", "2024 x 1 00ff 12ab u64 41",
+                     "2024 6 1 00ff 12ab text a\\qb"] {
+            assert!(parse_line(line).is_none(), "{line}");
+        }
+    }
+
+    #[test]
+    fn saves_and_reloads_the_answers() {
+        let path = temp_file("reload");
+        let solution = registry::find(2024, 1, 1).unwrap();
+        let answers = [Answer::from("007"), Answer::Text("#.\\\n.#".to_string()), Answer::from(u64::MAX)];
+
+        let mut cache = AnswerCache::load(&path).unwrap();
+        for (idx, answer) in answers.iter().enumerate() {
+            cache.insert(solution, &format!("input {idx}"), answer);
+        }
+        cache.save().unwrap();
+
+        let cache = AnswerCache::load(&path).unwrap();
+        for (idx, answer) in answers.iter().enumerate() {
+            let cached = cache.get(solution, &format!("input {idx}")).unwrap();
+            assert_eq!((cached.kind(), cached.to_string()), (answer.kind(), answer.to_string()));
+        }
+        assert!(cache.get(solution, "another input").is_none());
+        assert!(cache.get(registry::find(2024, 1, 2).unwrap(), "input 0").is_none());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn invalidated_by_the_sources() {
+        let solution = registry::find(2024, 6, 1).unwrap();
+        let changed = Solution { source_hash: "changed", ..*solution };
+        let mut cache = AnswerCache::load(&temp_file("sources")).unwrap();
+
+        cache.insert(solution, "input", &Answer::from(41));
+        assert_eq!(cache.get(solution, "input"), Some(Answer::from(41)));
+        assert_eq!(cache.get(&changed, "input"), None);
+
+        cache.insert(&changed, "input", &Answer::from(42));
+        assert_eq!(cache.get(&changed, "input"), Some(Answer::from(42)));
+        assert_eq!(cache.get(solution, "input"), None);
+    }
+
+    #[test]
+    fn drops_another_format() {
+        let path = temp_file("format");
+        fs::write(&path, "# Answers of `aoc run`: year day part input_hash source_hash answer\n2024 6 1 00ff 12ab 007\n").unwrap();
+
+        let cache = AnswerCache::load(&path).unwrap();
+        assert!(cache.entries.is_empty());
+        cache.save().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), format!("{HEADER}\n"));
+
+        fs::write(&path, format!("{HEADER}\n2024 6 1 00ff 12ab 007\n")).unwrap();
+        assert_eq!(AnswerCache::load(&path).unwrap_err(), format!("Invalid answer cache '{}': line 2", path.display()));
+
+        let _ = fs::remove_file(&path);
+    }
+}
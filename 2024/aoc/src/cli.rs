@@ -0,0 +1,164 @@
+/* Helpers shared by the commands of `aoc`.
+ */
+use crate::registry::{self, Solution};
+use aoc_common::args::Args;
+use aoc_common::input::{self, InputSource};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_YEAR: u16 = 2024;
+pub const INPUT_DIR: &str = "../input_data";
+
+/// Input of a solution: the one given by `--input` (`-` for the standard input), else the example
+/// with `--test`, else the standard input if it is a pipe or a file, else the personal input. A
+/// command solving several days checks the standard input with `check_piped_input` first.
+pub fn input_source(solution: &Solution, args: &Args) -> InputSource {
+    match args.value("--input") {
+        Some(arg) => InputSource::from_arg(arg),
+        None if args.flag("--test") => InputSource::File(input_file(solution, true)),
+        None => InputSource::select(None, input_file(solution, false)),
+    }
+}
+
+/// Checks that the standard input is not taken for the input of several days, when something is
+/// piped into it and the solutions of more than one day are selected: it must then be given
+/// explicitly with `--input -`.
+pub fn check_piped_input(solutions: &[&Solution], args: &Args) -> Result<(), String> {
+    let several_inputs = solutions.iter().any(|s| s.input != solutions[0].input);
+    if several_inputs && args.value("--input").is_none() && !args.flag("--test") && input::stdin_is_piped() {
+        return Err("The standard input can only be the input of a single day: select it with '--day', or give the \
+                    standard input to every selected day with '--input -'".to_string());
+    }
+    Ok(())
+}
+
+/// Personal input of a solution, or its example when `test` is set.
+pub fn input_file(solution: &Solution, test: bool) -> PathBuf {
+    match test {
+        true => format!("{INPUT_DIR}/{}_test.txt", solution.input).into(),
+        false => format!("{INPUT_DIR}/{}.txt", solution.input).into(),
+    }
+}
+
+/// Expected answers of the personal inputs, or of the examples when `test` is set, written
+/// `2024/6/1 = 41`.
+pub fn answers_file(test: bool) -> PathBuf {
+    match test {
+        true => format!("{INPUT_DIR}/answers_test.txt").into(),
+        false => format!("{INPUT_DIR}/answers.txt").into(),
+    }
+}
+
+/// Every input of a solution in the input directory and its subfolders, e.g. the personal inputs
+/// of several people: the files named after the input, such as `aoc_06.txt`, `aoc_06_test.txt` or
+/// `alice/aoc_06.txt`.
+pub fn all_input_files(solution: &Solution) -> Result<Vec<PathBuf>, String> {
+    input_files_in(Path::new(INPUT_DIR), solution.input)
+}
+
+/// Files of `dir` and of its subfolders named `<prefix>.txt`, or `<prefix>` followed by anything
+/// but a digit, sorted.
+fn input_files_in(dir: &Path, prefix: &str) -> Result<Vec<PathBuf>, String> {
+    fn walk(dir: &Path, prefix: &str, files: &mut Vec<PathBuf>) -> Result<(), String> {
+        let entries = fs::read_dir(dir).map_err(|e| format!("Could not read '{}': {e}", dir.display()))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            if path.is_dir() {
+                walk(&path, prefix, files)?;
+            }
+            else if let Some(rest) = name.strip_suffix(".txt").and_then(|n| n.strip_prefix(prefix)) {
+                /* `aoc_1` must not match the inputs of `aoc_10` */
+                if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+                    files.push(path);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    walk(dir, prefix, &mut files)?;
+    files.sort();
+    Ok(files)
+}
+
+pub fn label(solution: &Solution) -> String {
+    format!("{} day {:02} part {}", solution.year, solution.day, solution.part)
+}
+
+/// Selects the solutions from the `--year`, `--day`, `--part` and `--tag` options. Without `--day`,
+/// every solution of the year is selected unless `day_required` is set and no tag is given.
+/// Several tags select the solutions which have all of them.
+pub fn selected_solutions(args: &Args, day_required: bool) -> Result<Vec<&'static Solution>, String> {
+    let year = args.parsed_value("--year")?.unwrap_or(DEFAULT_YEAR);
+    let day: Option<u8> = args.parsed_value("--day")?;
+    let part: Option<u8> = args.parsed_value("--part")?;
+    let tags = args.values("--tag");
+
+    let known_tags = registry::tags();
+    if let Some(tag) = tags.iter().find(|t| !known_tags.contains(t)) {
+        return Err(format!("Unknown tag '{tag}', expected one of: {}", known_tags.join(", ")));
+    }
+
+    let mut solutions: Vec<&Solution> = match (day, part) {
+        (None, _) if day_required && tags.is_empty() => return Err("Missing option '--day'".to_string()),
+        (None, _) => registry::of_year(year),
+        (Some(day), Some(part)) => registry::find(year, day, part).into_iter().collect(),
+        (Some(day), None) => registry::of_day(year, day),
+    };
+    solutions.retain(|s| tags.iter().all(|t| s.puzzle().is_some_and(|p| p.has_tag(t))));
+
+    if solutions.is_empty() {
+        return match day {
+            _ if !tags.is_empty() => Err(format!("No solution found for year {year} with tag(s) {}", tags.join(", "))),
+            Some(day) => Err(format!("No solution found for year {year}, day {day}")),
+            None => Err(format!("No solution found for year {year}")),
+        };
+    }
+
+    Ok(solutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|a| a.to_string()), &["--day", "--input"]).unwrap()
+    }
+
+    /* The standard input of the tests may be piped or not, only the selections which never take it
+     * for the input of several days are checked */
+    #[test]
+    fn piped_input_of_a_single_day() {
+        let day = registry::of_day(2024, 6);
+        let year = registry::of_year(2024);
+
+        assert_eq!(check_piped_input(&day, &args(&["--day", "6"])), Ok(()));
+        assert_eq!(check_piped_input(&day[..1], &args(&["--day", "6"])), Ok(()));
+        assert_eq!(check_piped_input(&year, &args(&["--input", "-"])), Ok(()));
+        assert_eq!(check_piped_input(&year, &args(&["--input", "aoc_06.txt"])), Ok(()));
+        assert_eq!(check_piped_input(&year, &args(&["--test"])), Ok(()));
+    }
+
+    #[test]
+    fn input_files_of_a_directory() {
+        let dir = std::env::temp_dir().join(format!("aoc_inputs_{}", std::process::id()));
+        fs::create_dir_all(dir.join("alice")).unwrap();
+        for name in ["input.txt", "input_2.txt", "input_test.txt", "alice/input.txt", "input2.txt", "input.md", "other.txt"] {
+            fs::write(dir.join(name), "1\n").unwrap();
+        }
+
+        let files = input_files_in(&dir, "input").unwrap();
+        let names: Vec<String> = files.iter().map(|f| f.strip_prefix(&dir).unwrap().display().to_string()).collect();
+        assert_eq!(names, ["alice/input.txt", "input.txt", "input_2.txt", "input_test.txt"]);
+
+        assert!(input_files_in(&dir, "missing").unwrap().is_empty());
+        let missing = dir.join("missing");
+        assert_eq!(input_files_in(&missing, "input").unwrap_err(),
+                   format!("Could not read '{}': No such file or directory (os error 2)", missing.display()));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
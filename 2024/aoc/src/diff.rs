@@ -0,0 +1,126 @@
+/* `aoc diff`: differential testing of two implementations of the same part.
+ *
+ * Both implementations solve the example, the personal input, then inputs of `aoc gen`, until their
+ * answers diverge. They agree when they find the same answer or when both fail, e.g. on a generated
+ * input that the puzzle does not allow. The generated input of a divergence is saved, so that it can
+ * be solved again with `aoc run --input`.
+ */
+use crate::cli::{self, DEFAULT_YEAR, INPUT_DIR};
+use crate::generate;
+use crate::registry::{self, Solution};
+use aoc_common::args::Args;
+use aoc_common::runner::{self, Outcome, RunOptions};
+use std::fs;
+use std::path::PathBuf;
+
+pub const USAGE: &str = "aoc diff --day <N> [--part <P>] --impl <A> --impl <B> [--year <Y>] [--generated <N>] [--seed <S>] [--timeout <DURATION>]";
+
+const DEFAULT_GENERATED: u64 = 20;
+
+/// Input solved by both implementations.
+struct Case {
+    name: String,
+    input: String,
+    generated: bool,
+}
+
+/// Whether two outcomes agree: the same answer, or no answer at all.
+pub fn agree(a: &Outcome, b: &Outcome) -> bool {
+    match (a, b) {
+        (Outcome::Solved(a), Outcome::Solved(b)) => a == b,
+        (Outcome::Solved(_), _) | (_, Outcome::Solved(_)) => false,
+        _ => true,
+    }
+}
+
+/// Implementation of a part named `name`.
+pub fn implementation(year: u16, day: u8, part: u8, name: &str) -> Option<&'static Solution> {
+    registry::implementations(year, day, part).into_iter().find(|s| s.implementation == name)
+}
+
+/// Example and personal inputs when they exist, then `count` generated inputs.
+fn cases(solution: &Solution, count: u64, seed: u64) -> Vec<Case> {
+    let mut cases: Vec<Case> = [true, false].into_iter().filter_map(|test| {
+        let path = cli::input_file(solution, test);
+        let input = fs::read_to_string(&path).ok()?;
+        Some(Case { name: path.display().to_string(), input, generated: false })
+    }).collect();
+
+    for seed in seed..seed + count {
+        match generate::generate(solution.year, solution.day, seed) {
+            Some(input) => cases.push(Case { name: format!("generated input, seed {seed}"), input, generated: true }),
+            None => break,
+        }
+    }
+
+    cases
+}
+
+/// Compares the implementations of a part, returns whether they agree on every input.
+fn compare(a: &Solution, b: &Solution, count: u64, seed: u64, options: &RunOptions) -> bool {
+    let label = cli::label(a);
+    let cases = cases(a, count, seed);
+
+    for case in &cases {
+        let outcome_a = runner::run_part(a.solve, &case.input, options).outcome;
+        let outcome_b = runner::run_part(b.solve, &case.input, options).outcome;
+        if agree(&outcome_a, &outcome_b) {
+            continue;
+        }
+
+        println!("{label}: diverge on {}", case.name);
+        println!("  {}: {outcome_a}", a.implementation);
+        println!("  {}: {outcome_b}", b.implementation);
+        if case.generated {
+            let path = PathBuf::from(format!("{INPUT_DIR}/diff_{}_{:02}_{}.txt", a.year, a.day, a.part));
+            match fs::write(&path, &case.input) {
+                Ok(()) => println!("  Input saved to '{}'", path.display()),
+                Err(e) => println!("  Could not write '{}': {e}", path.display()),
+            }
+        }
+        return false;
+    }
+
+    println!("{label}: {} and {} agree on {} inputs", a.implementation, b.implementation, cases.len());
+    true
+}
+
+/// Compares the two `--impl` of the parts of `--day`, or of `--part` only.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let year = args.parsed_value("--year")?.unwrap_or(DEFAULT_YEAR);
+    let day: u8 = args.parsed_value("--day")?.ok_or("Missing option '--day'")?;
+    let part: Option<u8> = args.parsed_value("--part")?;
+    let count = args.parsed_value("--generated")?.unwrap_or(DEFAULT_GENERATED);
+    let seed = args.parsed_value("--seed")?.unwrap_or(0);
+    let options = RunOptions { timeout: args.duration("--timeout")?, ..RunOptions::default() };
+
+    let [name_a, name_b] = args.values("--impl")[..] else {
+        return Err("Expected two implementations, with '--impl <A> --impl <B>'".to_string());
+    };
+
+    let parts: Vec<u8> = match part {
+        Some(part) => vec![part],
+        None => registry::of_day(year, day).iter().map(|s| s.part).collect(),
+    };
+    let pairs: Vec<(&Solution, &Solution)> = parts.iter()
+        .filter_map(|&part| Some((implementation(year, day, part, name_a)?, implementation(year, day, part, name_b)?)))
+        .collect();
+
+    if pairs.is_empty() {
+        let mut known: Vec<&str> = parts.iter()
+                                        .flat_map(|&part| registry::implementations(year, day, part))
+                                        .map(|s| s.implementation)
+                                        .collect();
+        known.sort();
+        known.dedup();
+        return Err(format!("No part of year {year}, day {day} has the implementations '{name_a}' and '{name_b}', known: {}",
+                           known.join(", ")));
+    }
+
+    let mut all_agree = true;
+    for (a, b) in pairs {
+        all_agree &= compare(a, b, count, seed, &options);
+    }
+
+    Ok(all_agree)
+}
@@ -0,0 +1,247 @@
+/* `aoc examples`: example input and answers of a day, from its puzzle page saved from the site.
+ *
+ * Each part of the puzzle is an `<article>` of the page. The example input is a `<pre><code>`
+ * block of the first part, the first one unless `--block` picks another. The answer of the example
+ * of a part is the last emphasised code of its article, e.g. `<code><em>41</em></code>`, which is
+ * how the puzzles end their explanation.
+ *
+ * The input is written to the example file of the day, and the answers to the expected answers of
+ * the examples, which `aoc report --test` checks.
+ */
+use crate::cli::{self, DEFAULT_YEAR};
+use crate::registry;
+use crate::report;
+use aoc_common::args::Args;
+use std::fs;
+use std::path::Path;
+
+pub const USAGE: &str = "aoc examples --from <PAGE.html> [--day <N>] [--year <Y>] [--block <K>] [--force]";
+
+/// Text of the page between each `open` tag, which may have attributes, and the next `close` tag.
+fn elements<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut result = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find(open) {
+        let Some(content_start) = rest[start..].find('>').map(|idx| start + idx + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find(close).map(|idx| content_start + idx) else {
+            break;
+        };
+        result.push(&rest[content_start..end]);
+        rest = &rest[end + close.len()..];
+    }
+
+    result
+}
+
+/// Text of an HTML fragment, without its tags and with its entities decoded.
+fn text(html: &str) -> String {
+    let mut without_tags = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => without_tags.push(c),
+            _ => {},
+        }
+    }
+
+    let mut result = String::with_capacity(without_tags.len());
+    let mut rest = without_tags.as_str();
+    while let Some(idx) = rest.find('&') {
+        result.push_str(&rest[..idx]);
+        rest = &rest[idx..];
+
+        let entity = rest.find(';').filter(|&end| end <= 10).map(|end| &rest[1..end]);
+        let decoded = entity.and_then(|entity| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok().and_then(char::from_u32),
+                None => entity.strip_prefix('#').and_then(|d| d.parse().ok()).and_then(char::from_u32),
+            },
+        });
+
+        match (entity, decoded) {
+            (Some(entity), Some(c)) => {
+                result.push(c);
+                rest = &rest[entity.len() + 2..];
+            },
+            _ => {
+                result.push('&');
+                rest = &rest[1..];
+            },
+        }
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Day and year of the page, from its title `Day 6 - Advent of Code 2024`.
+fn page_day(html: &str) -> Option<(u16, u8)> {
+    let title = text(elements(html, "<title", "</title>").first()?);
+    let (day, year) = title.strip_prefix("Day ")?.split_once(" - Advent of Code ")?;
+    Some((year.trim().parse().ok()?, day.trim().parse().ok()?))
+}
+
+/// Last emphasised code of a part, the answer of its example.
+fn example_answer(article: &str) -> Option<String> {
+    let codes = elements(article, "<code", "</code>");
+    codes.iter().rev().find(|code| code.starts_with("<em>")).map(|code| text(code))
+}
+
+/// Articles of the parts of the page. A page saved before the first part was solved may have none,
+/// the whole page is then the first part.
+fn articles(html: &str) -> Vec<&str> {
+    match elements(html, "<article", "</article>") {
+        articles if articles.is_empty() => vec![html],
+        articles => articles,
+    }
+}
+
+/// Text of the `<pre>` blocks of a part, the candidate example inputs.
+fn example_blocks(article: &str) -> Vec<String> {
+    elements(article, "<pre", "</pre>").iter().map(|b| text(b)).collect()
+}
+
+/// Answers of the examples of the parts whose article has one, with the number of their part.
+fn example_answers(articles: &[&str]) -> Vec<(u8, String)> {
+    articles.iter().zip(1..).filter_map(|(article, part)| Some((part, example_answer(article)?))).collect()
+}
+
+/// Adds the answers to the expected answers file, replacing the previous ones of the same parts.
+fn write_answers(path: &Path, answers: &[((u16, u8, u8), String)]) -> Result<(), String> {
+    let text = if path.is_file() {
+        fs::read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?
+    } else {
+        "# Answers of the examples: <year>/<day>/<part> = <answer>\n".to_string()
+    };
+
+    /* Checks the file before changing it */
+    report::load_expected(path)?;
+    let keys: Vec<String> = answers.iter().map(|((year, day, part), _)| format!("{year}/{day}/{part}")).collect();
+    let mut lines: Vec<String> = text.lines()
+                                     .filter(|l| !l.split_once('=').is_some_and(|(key, _)| keys.contains(&key.trim().to_string())))
+                                     .map(str::to_string)
+                                     .collect();
+    lines.extend(keys.iter().zip(answers).map(|(key, (_, answer))| format!("{key} = {answer}")));
+
+    fs::write(path, lines.join("\n") + "\n").map_err(|e| format!("Could not write '{}': {e}", path.display()))
+}
+
+/// Writes the example input and the answers found in the page of `--from`.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let page = args.value("--from").ok_or("Missing option '--from'")?;
+    let html = fs::read_to_string(page).map_err(|e| format!("Could not read '{page}': {e}"))?;
+
+    let (year, day) = match (args.parsed_value("--year")?, args.parsed_value("--day")?, page_day(&html)) {
+        (year, Some(day), _) => (year.unwrap_or(DEFAULT_YEAR), day),
+        (year, None, Some((page_year, page_day))) => (year.unwrap_or(page_year), page_day),
+        (_, None, None) => return Err(format!("Could not find the day in '{page}', give it with '--day'")),
+    };
+    let solutions = registry::of_day(year, day);
+    let solution = solutions.first().ok_or(format!("No solution found for year {year}, day {day}"))?;
+
+    let articles = articles(&html);
+    let blocks = example_blocks(articles[0]);
+    if blocks.is_empty() {
+        return Err(format!("No example block found in '{page}'"));
+    }
+    let block: usize = args.parsed_value("--block")?.unwrap_or(1);
+    let example = blocks.get(block.wrapping_sub(1))
+                        .ok_or(format!("Invalid block {block}, the page has {} example block(s)", blocks.len()))?;
+
+    println!("{year} day {day}: {} example block(s), using block {block}", blocks.len());
+    for (idx, b) in blocks.iter().enumerate() {
+        let first_line = b.lines().next().unwrap_or_default();
+        println!("  {}{}: {} lines, {first_line}", if idx + 1 == block { "*" } else { " " }, idx + 1, b.lines().count());
+    }
+
+    let path = cli::input_file(solution, true);
+    match fs::read_to_string(&path) {
+        Ok(current) if current == *example => println!("Example unchanged in '{}'", path.display()),
+        Ok(_) if !args.flag("--force") => {
+            return Err(format!("'{}' already exists with another example, use '--force' to replace it", path.display()));
+        },
+        _ => {
+            fs::write(&path, example).map_err(|e| format!("Could not write '{}': {e}", path.display()))?;
+            println!("Example written to '{}'", path.display());
+        },
+    }
+
+    let answers: Vec<((u16, u8, u8), String)> = example_answers(&articles).into_iter()
+        .filter(|(part, _)| solutions.iter().any(|s| s.part == *part))
+        .map(|(part, answer)| ((year, day, part), answer))
+        .collect();
+    for ((_, _, part), answer) in &answers {
+        println!("Answer of the example of part {part}: {answer}");
+    }
+    if !answers.is_empty() {
+        let answers_file = cli::answers_file(true);
+        write_answers(&answers_file, &answers)?;
+        println!("Answers written to '{}'", answers_file.display());
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Puzzle page of day 6 saved once both parts were solved, shortened.
+    const PAGE: &str = include_str!("../fixtures/day06_page.html");
+
+    #[test]
+    fn elements_of_the_page() {
+        assert_eq!(elements("<p>a</p> <p class=\"x\">b</p>", "<p", "</p>"), ["a", "b"]);
+        assert_eq!(elements("<p>a</p><p>unclosed", "<p", "</p>"), ["a"]);
+        assert_eq!(elements("no tag", "<p", "</p>"), Vec::<&str>::new());
+
+        assert_eq!(articles(PAGE).len(), 2);
+        assert_eq!(page_day(PAGE), Some((2024, 6)));
+        assert_eq!(page_day("<title>Advent of Code</title>"), None);
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(text("a &lt;-&gt; b &amp;&amp; c"), "a <-> b && c");
+        assert_eq!(text("&quot;&apos;&nbsp;&#65;&#x42;&#X43;"), "\"' ABC");
+        assert_eq!(text("<em>x</em> &unknown; & &lt"), "x &unknown; & &lt");
+        assert_eq!(text("&amp;lt;"), "&lt;");
+    }
+
+    #[test]
+    fn example_blocks_of_the_first_part() {
+        let blocks = example_blocks(articles(PAGE)[0]);
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0], "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+                               ..........\n.#..^.....\n........#.\n#.........\n......#...\n");
+        assert_eq!(blocks[1], "....#.....\n....^....#\n");
+        assert_eq!(blocks[2], "&lt;^&gt; & <v>\n");
+    }
+
+    #[test]
+    fn answers_of_the_examples() {
+        assert_eq!(example_answer("<code>41</code> <code><em>6</em></code> <em>not code</em> <code>7</code>"), Some("6".to_string()));
+        assert_eq!(example_answer("<code>41</code>"), None);
+        assert_eq!(example_answers(&articles(PAGE)), [(1, "41".to_string()), (2, "6".to_string())]);
+
+        /* Saved before the first part was solved: no second part, and no article at all */
+        let first_part = &PAGE[..PAGE.find("<p>Your puzzle answer was").unwrap()];
+        assert_eq!(example_answers(&articles(first_part)), [(1, "41".to_string())]);
+        let without_article = first_part.replace("<article class=\"day-desc\">", "").replace("</article>", "");
+        assert_eq!(articles(&without_article), [without_article.as_str()]);
+        assert_eq!(example_answers(&articles(&without_article)), [(1, "41".to_string())]);
+        assert_eq!(example_blocks(articles(&without_article)[0]).len(), 3);
+    }
+}
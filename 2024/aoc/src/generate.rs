@@ -0,0 +1,274 @@
+/* `aoc gen`: random inputs of the puzzles, e.g. to try the solvers in a pipeline
+ * (`aoc gen --day 9 | aoc run --day 9`) or to compare two implementations with `aoc diff`.
+ *
+ * The inputs follow the shape of the real ones, smaller so that every solver is fast on them. The
+ * same seed always gives the same input.
+ */
+use crate::cli::DEFAULT_YEAR;
+use aoc_common::args::Args;
+use std::collections::HashSet;
+
+pub const USAGE: &str = "aoc gen --day <N> [--year <Y>] [--seed <S>]";
+
+/// Small pseudo-random generator (SplitMix64), enough for inputs and reproducible everywhere.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number between `low` and `high`, both included.
+    pub fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high - low + 1) as u64) as i64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 <= probability
+    }
+
+    pub fn pick<T: Copy>(&mut self, items: &[T]) -> T {
+        items[self.between(0, items.len() as i64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.between(0, idx as i64) as usize);
+        }
+    }
+}
+
+/// Random input of a day, `None` when there is no generator for it.
+pub fn generate(year: u16, day: u8, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed ^ ((year as u64) << 8 | day as u64).wrapping_mul(0x2545f4914f6cdd1d));
+
+    let input = match (year, day) {
+        (2024, 1) => location_lists(&mut rng),
+        (2024, 2) => reports(&mut rng),
+        (2024, 3) => corrupted_memory(&mut rng),
+        (2024, 4) => word_search(&mut rng),
+        (2024, 5) => print_queue(&mut rng),
+        (2024, 6) => guard_map(&mut rng),
+        (2024, 7) => equations(&mut rng),
+        (2024, 8) => antenna_map(&mut rng),
+        (2024, 9) => disk_map(&mut rng),
+        (2024, 10) => topographic_map(&mut rng),
+        _ => return None,
+    };
+
+    Some(input)
+}
+
+fn lines(rows: impl IntoIterator<Item = String>) -> String {
+    rows.into_iter().map(|r| r + "\n").collect()
+}
+
+/// Map of `size` rows of `size` cells drawn by `cell`.
+fn square_map<F: FnMut(usize, usize) -> char>(size: usize, mut cell: F) -> Vec<Vec<char>> {
+    (0..size).map(|y| (0..size).map(|x| cell(x, y)).collect()).collect()
+}
+
+fn map_text(map: &[Vec<char>]) -> String {
+    lines(map.iter().map(|row| row.iter().collect()))
+}
+
+/* The right list reuses numbers of the left one, so that the similarity is not zero */
+fn location_lists(rng: &mut Rng) -> String {
+    let left: Vec<i64> = (0..rng.between(10, 50)).map(|_| rng.between(10000, 99999)).collect();
+
+    lines(left.iter().map(|l| {
+        let right = if rng.chance(0.3) { rng.pick(&left) } else { rng.between(10000, 99999) };
+        format!("{l}   {right}")
+    }))
+}
+
+/* Mostly safe reports, with some bad steps */
+fn reports(rng: &mut Rng) -> String {
+    lines((0..rng.between(5, 20)).map(|_| {
+        let direction = rng.pick(&[-1, 1]);
+        let mut level = rng.between(40, 60);
+        let mut levels = vec![level];
+
+        for _ in 1..rng.between(5, 8) {
+            let step = match rng.chance(0.1) {
+                true => rng.pick(&[0, 4, 5, -2]),
+                false => rng.between(1, 3),
+            };
+            level += direction * step;
+            levels.push(level);
+        }
+
+        levels.iter().map(|l| l.to_string()).collect::<Vec<_>>().join(" ")
+    }))
+}
+
+fn corrupted_memory(rng: &mut Rng) -> String {
+    const NOISE: &[char] = &['x', 'm', 'u', 'l', '(', ')', '[', ']', ',', '!', '@', '#', '%', '^', '&', '*', '{', '}',
+                             '<', '>', '?', '\'', '+', '-', ' ', 'd', 'o', 'n', 't', 'w', 'h', 'e', 'r', 'f', '1'];
+
+    lines((0..rng.between(1, 3)).map(|_| {
+        let mut line = String::new();
+        for _ in 0..rng.between(10, 40) {
+            let (a, b) = (rng.between(0, 999), rng.between(0, 999));
+            match rng.between(0, 9) {
+                0..=3 => line += &format!("mul({a},{b})"),
+                4 => line += &format!("mul({a},{b}]"),
+                5 => line += &format!("mul ( {a},{b})"),
+                6 => line += "do()",
+                7 => line += "don't()",
+                _ => line.extend((0..rng.between(1, 5)).map(|_| rng.pick(NOISE))),
+            }
+        }
+        line
+    }))
+}
+
+fn word_search(rng: &mut Rng) -> String {
+    let size = rng.between(5, 15) as usize;
+    map_text(&square_map(size, |_, _| rng.pick(&['X', 'M', 'A', 'S'])))
+}
+
+/* The rules give the order of every pair of pages, as in the real input */
+fn print_queue(rng: &mut Rng) -> String {
+    let mut pages: Vec<i64> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(rng.between(5, 15) as usize);
+
+    let mut rules = Vec::new();
+    for (idx, first) in pages.iter().enumerate() {
+        for second in &pages[idx + 1..] {
+            rules.push(format!("{first}|{second}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let updates = (0..rng.between(3, 10)).map(|_| {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(rng.between(1, (pages.len() as i64 - 1) / 2) as usize * 2 + 1);
+        if rng.chance(0.5) {
+            update.sort_by_key(|page| pages.iter().position(|p| p == page));
+        }
+        update.iter().map(|p| p.to_string()).collect::<Vec<_>>().join(",")
+    });
+
+    lines(rules) + "\n" + &lines(updates)
+}
+
+/// Whether the guard leaves the map, instead of walking in a loop.
+fn guard_leaves(map: &[Vec<char>], x: usize, y: usize) -> bool {
+    const DIRECTIONS: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    let size = map.len() as i64;
+    let (mut x, mut y, mut direction) = (x as i64, y as i64, 0);
+    let mut seen = HashSet::new();
+
+    while seen.insert((x, y, direction)) {
+        let (dx, dy) = DIRECTIONS[direction];
+        let (next_x, next_y) = (x + dx, y + dy);
+        if next_x < 0 || next_y < 0 || next_x >= size || next_y >= size {
+            return true;
+        }
+        if map[next_y as usize][next_x as usize] == '#' {
+            direction = (direction + 1) % 4;
+        }
+        else {
+            (x, y) = (next_x, next_y);
+        }
+    }
+
+    false
+}
+
+/* The first part assumes that the guard leaves the map: the maps where it loops are drawn again */
+fn guard_map(rng: &mut Rng) -> String {
+    loop {
+        let size = rng.between(6, 20) as usize;
+        let density = rng.between(5, 20) as f64 / 100.0;
+        let mut map = square_map(size, |_, _| if rng.chance(density) { '#' } else { '.' });
+        let (x, y) = (rng.between(0, size as i64 - 1) as usize, rng.between(0, size as i64 - 1) as usize);
+        map[y][x] = '^';
+
+        if guard_leaves(&map, x, y) {
+            return map_text(&map);
+        }
+    }
+}
+
+/* Half of the equations are built with the operators, the others are likely impossible */
+fn equations(rng: &mut Rng) -> String {
+    lines((0..rng.between(5, 15)).map(|_| {
+        let operands: Vec<i64> = (0..rng.between(2, 6)).map(|_| rng.between(1, 20)).collect();
+        let result = match rng.chance(0.5) {
+            true => operands[1..].iter().fold(operands[0], |acc, o| match rng.between(0, 2) {
+                0 => acc + o,
+                1 => acc * o,
+                _ => format!("{acc}{o}").parse().unwrap_or(acc),
+            }),
+            false => rng.between(1, 10000),
+        };
+        format!("{result}: {}", operands.iter().map(|o| o.to_string()).collect::<Vec<_>>().join(" "))
+    }))
+}
+
+fn antenna_map(rng: &mut Rng) -> String {
+    const FREQUENCIES: &[char] = &['0', '1', 'a', 'b', 'A', 'B', 'z', 'Z'];
+    let size = rng.between(8, 20) as usize;
+    let mut map = square_map(size, |_, _| '.');
+
+    for _ in 0..rng.between(1, 4) {
+        let frequency = rng.pick(FREQUENCIES);
+        for _ in 0..rng.between(2, 4) {
+            let (x, y) = (rng.between(0, size as i64 - 1) as usize, rng.between(0, size as i64 - 1) as usize);
+            map[y][x] = frequency;
+        }
+    }
+
+    map_text(&map)
+}
+
+/* Files have at least one block, free spaces may be empty */
+fn disk_map(rng: &mut Rng) -> String {
+    let files = rng.between(5, 30);
+    let mut line = String::new();
+
+    for idx in 0..files {
+        line += &rng.between(1, 9).to_string();
+        if idx + 1 < files {
+            line += &rng.between(0, 9).to_string();
+        }
+    }
+
+    line + "\n"
+}
+
+/* Heights rise along the diagonals, with noise, so that there are trails to find */
+fn topographic_map(rng: &mut Rng) -> String {
+    let size = rng.between(8, 16) as usize;
+    let map = square_map(size, |x, y| {
+        let noise = if rng.chance(0.1) { rng.between(-1, 1) } else { 0 };
+        let height = ((x + y) as i64 + noise).rem_euclid(10);
+        char::from_digit(height as u32, 10).unwrap_or('0')
+    });
+
+    map_text(&map)
+}
+
+/// Prints a random input of `--day`, so that it can be piped into the solvers.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let year = args.parsed_value("--year")?.unwrap_or(DEFAULT_YEAR);
+    let day: u8 = args.parsed_value("--day")?.ok_or("Missing option '--day'")?;
+    let seed = args.parsed_value("--seed")?.unwrap_or(0);
+
+    let input = generate(year, day, seed).ok_or(format!("No input generator for year {year}, day {day}"))?;
+    print!("{input}");
+
+    Ok(true)
+}
@@ -0,0 +1,44 @@
+/* `aoc info`: metadata of the puzzles and of their solutions.
+ */
+use crate::cli::{self, DEFAULT_YEAR};
+use crate::registry::{self, Puzzle};
+use aoc_common::args::Args;
+
+pub const USAGE: &str = "aoc info [--day <N>] [--year <Y>] [--tag <TAG>]...";
+
+fn describe(puzzle: &Puzzle) {
+    println!("{} day {}: {}", puzzle.year, puzzle.day, puzzle.title);
+    println!("  Tags: {}", puzzle.tags.join(", "));
+
+    for solution in registry::of_day(puzzle.year, puzzle.day) {
+        println!("  Part {}: {} (crate {})", solution.part, puzzle.url(solution.part), solution.krate);
+    }
+    if let Some(solution) = registry::of_day(puzzle.year, puzzle.day).first() {
+        let input = cli::input_file(solution, false);
+        println!("  Input: {} ({})", input.display(), if input.is_file() { "present" } else { "missing" });
+    }
+}
+
+/// Describes the puzzle of `--day`, else lists the puzzles of the year, restricted to the ones
+/// having all the `--tag`.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let year = args.parsed_value("--year")?.unwrap_or(DEFAULT_YEAR);
+    let day: Option<u8> = args.parsed_value("--day")?;
+
+    if let Some(day) = day {
+        let puzzle = registry::puzzle(year, day).ok_or(format!("No puzzle found for year {year}, day {day}"))?;
+        describe(puzzle);
+        return Ok(true);
+    }
+
+    /* Same selection as the other commands, by year and tags */
+    let solutions = cli::selected_solutions(args, false)?;
+    let mut puzzles: Vec<&Puzzle> = solutions.iter().filter_map(|s| s.puzzle()).collect();
+    puzzles.dedup_by_key(|p| (p.year, p.day));
+
+    for puzzle in puzzles {
+        println!("{} day {:2}: {:<24} {}", puzzle.year, puzzle.day, puzzle.title, puzzle.tags.join(", "));
+    }
+
+    Ok(true)
+}
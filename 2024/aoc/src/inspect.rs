@@ -0,0 +1,63 @@
+/* `aoc inspect`: statistics of the input of a day, before writing its solver.
+ *
+ * The general statistics, such as the dimensions and the numbers of the input, are followed by the
+ * ones of the day when its solutions have an `inspect` hook, see the registry.
+ */
+use crate::cli;
+use crate::registry::Solution;
+use aoc_common::args::Args;
+use aoc_common::inspect::{self, Stat};
+
+pub const USAGE: &str = "aoc inspect [--day <N>] [--year <Y>] [--tag <TAG>]... [--test | --input <FILE>]";
+
+fn print_stats(title: &str, stats: &[Stat]) {
+    let width = stats.iter().map(|s| s.name.len()).max().unwrap_or_default();
+
+    println!("  {title}");
+    for stat in stats {
+        println!("    {:<width$}  {}", stat.name, stat.value);
+    }
+}
+
+/// Prints the statistics of the input of a day, returns whether it could be read and inspected.
+fn inspect_day(solution: &Solution, args: &Args) -> bool {
+    let source = cli::input_source(solution, args);
+    println!("{} day {:02}, {source}", solution.year, solution.day);
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) => {
+            println!("  {e}");
+            return false;
+        },
+    };
+
+    print_stats("General", &inspect::general(&input));
+    match solution.inspect.map(|inspect| inspect(&input)) {
+        Some(Ok(stats)) => print_stats(&format!("Day {}", solution.day), &stats),
+        Some(Err(e)) => {
+            println!("  Day {}: {e}", solution.day);
+            return false;
+        },
+        None => {},
+    }
+
+    true
+}
+
+/// Inspects the inputs of the selected days.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let mut solutions = cli::selected_solutions(args, false)?;
+    solutions.dedup_by_key(|s| (s.year, s.day));
+    cli::check_piped_input(&solutions, args)?;
+
+    let mut success = true;
+    for (idx, solution) in solutions.iter().enumerate() {
+        if idx > 0 {
+            println!();
+        }
+        success &= inspect_day(solution, args);
+    }
+
+    Ok(success)
+}
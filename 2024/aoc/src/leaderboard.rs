@@ -0,0 +1,349 @@
+/* `aoc leaderboard`: analytics of a private leaderboard, from its JSON export.
+ *
+ * The JSON is read from a file, or downloaded with `curl` from `--url` or `$AOC_LEADERBOARD_URL`,
+ * e.g. `https://adventofcode.com/2024/leaderboard/private/view/<ID>.json` with the session cookie
+ * in `$AOC_SESSION`, or a local stub serving a saved export.
+ *
+ * The local scores are computed again from the times of the stars: for each part, the first member
+ * gets as many points as there are members, the second one point less, and so on.
+ */
+use aoc_common::args::Args;
+use aoc_common::json::Json;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+pub const USAGE: &str = "aoc leaderboard (--file <FILE.json> | --url <URL>) [--day <N>] [--format <table|json>]";
+
+const URL_VARIABLE: &str = "AOC_LEADERBOARD_URL";
+const SESSION_VARIABLE: &str = "AOC_SESSION";
+const LAST_DAY: u8 = 25;
+
+struct Member {
+    id: u64,
+    name: String,
+    /// Time of the stars, by day and part
+    stars: BTreeMap<(u8, u8), u64>,
+}
+
+impl Member {
+    fn last_star(&self) -> Option<u64> {
+        self.stars.values().max().copied()
+    }
+
+    /// A character by day: `*` for both parts, `+` for the first one only.
+    fn star_line(&self, days: u8) -> String {
+        (1..=days).map(|day| match (self.stars.contains_key(&(day, 1)), self.stars.contains_key(&(day, 2))) {
+            (true, true) => '*',
+            (true, false) => '+',
+            _ => '.',
+        }).collect()
+    }
+}
+
+struct Leaderboard {
+    event: u16,
+    members: Vec<Member>,
+}
+
+/// Place of a member in the ranking.
+struct Rank<'a> {
+    member: &'a Member,
+    score: u64,
+}
+
+/// Times of a member on both parts of a day, in seconds since the puzzle unlocked.
+struct DayResult<'a> {
+    member: &'a Member,
+    part_1: u64,
+    part_2: u64,
+}
+
+impl DayResult<'_> {
+    fn delta(&self) -> u64 {
+        self.part_2.saturating_sub(self.part_1)
+    }
+}
+
+fn parse_leaderboard(text: &str) -> Result<Leaderboard, String> {
+    let json = Json::parse(text)?;
+    let error = |what: &str| format!("Invalid leaderboard: {what}");
+
+    let event = json.get("event").and_then(Json::as_u64).ok_or_else(|| error("missing 'event'"))? as u16;
+    let members = json.get("members").and_then(Json::as_object).ok_or_else(|| error("missing 'members'"))?;
+
+    let members = members.values().map(|member| {
+        let id = member.get("id").and_then(Json::as_u64).ok_or_else(|| error("member without 'id'"))?;
+        let name = member.get("name").and_then(Json::as_str).map(str::to_string)
+                         .unwrap_or(format!("(anonymous user #{id})"));
+
+        let mut stars = BTreeMap::new();
+        let days = member.get("completion_day_level").and_then(Json::as_object).into_iter().flatten();
+        for (day, parts) in days {
+            let day: u8 = day.parse().map_err(|_| error(&format!("invalid day '{day}'")))?;
+            for (part, star) in parts.as_object().into_iter().flatten() {
+                let part: u8 = part.parse().map_err(|_| error(&format!("invalid part '{part}'")))?;
+                let time = star.get("get_star_ts").and_then(Json::as_u64)
+                               .ok_or_else(|| error(&format!("star of day {day} part {part} without 'get_star_ts'")))?;
+                stars.insert((day, part), time);
+            }
+        }
+
+        Ok(Member { id, name, stars })
+    }).collect::<Result<Vec<_>, String>>()?;
+
+    Ok(Leaderboard { event, members })
+}
+
+/// Configuration of curl which sends the session cookie, its value quoted as a string of curl.
+fn session_config(session: &str) -> String {
+    format!("cookie = \"session={}\"\n", session.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Text of the leaderboard from `--file`, else downloaded from `--url` or the URL variable.
+fn read_leaderboard(args: &Args) -> Result<String, String> {
+    if let Some(file) = args.value("--file") {
+        return fs::read_to_string(file).map_err(|e| format!("Could not read '{file}': {e}"));
+    }
+
+    let url = args.value("--url").map(str::to_string).or(std::env::var(URL_VARIABLE).ok())
+                  .ok_or(format!("Missing option '--file' or '--url', or variable '{URL_VARIABLE}'"))?;
+    /* The session goes through the configuration read on stdin, the arguments being visible to the
+     * other users of the machine */
+    let config = std::env::var(SESSION_VARIABLE).map(|session| session_config(&session)).unwrap_or_default();
+    let mut curl = Command::new("curl").args(["--silent", "--show-error", "--fail", "--location", "--config", "-", &url])
+                                       .stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped())
+                                       .spawn()
+                                       .map_err(|e| format!("Could not run curl to download '{url}': {e}"))?;
+    if let Some(mut stdin) = curl.stdin.take() {
+        stdin.write_all(config.as_bytes()).map_err(|e| format!("Could not give the session to curl: {e}"))?;
+    }
+
+    let output = curl.wait_with_output().map_err(|e| format!("Could not run curl to download '{url}': {e}"))?;
+    if !output.status.success() {
+        return Err(format!("Could not download '{url}': {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+    String::from_utf8(output.stdout).map_err(|_| format!("The leaderboard of '{url}' is not UTF-8"))
+}
+
+/// Members by decreasing score, then number of stars, then time of their last star.
+fn rankings(leaderboard: &Leaderboard) -> Vec<Rank<'_>> {
+    let mut scores: Vec<u64> = vec![0; leaderboard.members.len()];
+    let parts: Vec<(u8, u8)> = (1..=LAST_DAY).flat_map(|day| [(day, 1), (day, 2)]).collect();
+
+    for part in parts {
+        let mut finishers: Vec<(u64, usize)> = leaderboard.members.iter().enumerate()
+                                                          .filter_map(|(idx, m)| Some((*m.stars.get(&part)?, idx)))
+                                                          .collect();
+        finishers.sort();
+        for (place, (_, idx)) in finishers.into_iter().enumerate() {
+            scores[idx] += (leaderboard.members.len() - place) as u64;
+        }
+    }
+
+    let mut ranks: Vec<Rank> = leaderboard.members.iter().zip(scores).map(|(member, score)| Rank { member, score }).collect();
+    ranks.sort_by_key(|r| (std::cmp::Reverse(r.score), std::cmp::Reverse(r.member.stars.len()), r.member.last_star()));
+    ranks
+}
+
+/// Results of the members who solved both parts of `day`, the fastest on the second part first.
+fn day_results(leaderboard: &Leaderboard, day: u8) -> Vec<DayResult<'_>> {
+    let unlock = unlock_time(leaderboard.event, day);
+    let mut results: Vec<DayResult> = leaderboard.members.iter().filter_map(|member| Some(DayResult {
+        member,
+        part_1: member.stars.get(&(day, 1))?.saturating_sub(unlock),
+        part_2: member.stars.get(&(day, 2))?.saturating_sub(unlock),
+    })).collect();
+
+    results.sort_by_key(|r| (r.delta(), r.part_2));
+    results
+}
+
+/// Days since 1970-01-01 of a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// The puzzles unlock at midnight EST, 05:00 UTC.
+fn unlock_time(event: u16, day: u8) -> u64 {
+    (days_from_civil(event as i64, 12, day as i64) * 86400 + 5 * 3600) as u64
+}
+
+/// Date and time in UTC of a Unix timestamp.
+fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86400) as i64;
+    let seconds = timestamp % 86400;
+
+    /* Inverse of `days_from_civil` */
+    let shifted = days + 719468;
+    let era = shifted.div_euclid(146097);
+    let day_of_era = shifted - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{year}-{month:02}-{day:02} {:02}:{:02}:{:02} UTC", seconds / 3600, seconds / 60 % 60, seconds % 60)
+}
+
+fn format_duration(seconds: u64) -> String {
+    let time = format!("{:02}:{:02}:{:02}", seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+    match seconds / 86400 {
+        0 => time,
+        days => format!("{days}d {time}"),
+    }
+}
+
+fn print_tables(leaderboard: &Leaderboard, days: &[u8]) {
+    let ranks = rankings(leaderboard);
+    let last_day = leaderboard.members.iter().flat_map(|m| m.stars.keys().map(|(day, _)| *day)).max().unwrap_or(1);
+    let name_width = leaderboard.members.iter().map(|m| m.name.chars().count()).chain(["Member".len()]).max().unwrap_or_default();
+    let days_width = (last_day as usize).max("Days".len());
+
+    println!("Leaderboard {}, {} members", leaderboard.event, leaderboard.members.len());
+    println!("Rank  {:<name_width$}  Stars  Score  {:<days_width$}  Last star", "Member", "Days");
+    for (idx, rank) in ranks.iter().enumerate() {
+        let last_star = rank.member.last_star().map(format_timestamp).unwrap_or_default();
+        println!("{:>4}  {:<name_width$}  {:>5}  {:>5}  {:<days_width$}  {last_star}", idx + 1, rank.member.name,
+                 rank.member.stars.len(), rank.score, rank.member.star_line(last_day));
+    }
+
+    for &day in days {
+        let results = day_results(leaderboard, day);
+        if results.is_empty() {
+            continue;
+        }
+
+        println!("\nDay {day}: time since the unlock, and from part 1 to part 2");
+        println!("{:<name_width$}  {:>11}  {:>11}  {:>11}", "Member", "Part 1", "Part 2", "Delta");
+        for result in results {
+            println!("{:<name_width$}  {:>11}  {:>11}  {:>11}", result.member.name, format_duration(result.part_1),
+                     format_duration(result.part_2), format_duration(result.delta()));
+        }
+    }
+}
+
+fn to_json(leaderboard: &Leaderboard, days: &[u8]) -> Json {
+    let members = rankings(leaderboard).iter().enumerate().map(|(idx, rank)| Json::object([
+        ("rank", Json::from(idx as u64 + 1)),
+        ("id", Json::from(rank.member.id)),
+        ("name", Json::from(rank.member.name.as_str())),
+        ("stars", Json::from(rank.member.stars.len() as u64)),
+        ("score", Json::from(rank.score)),
+        ("last_star", Json::from(rank.member.last_star())),
+    ])).collect::<Vec<_>>();
+
+    let days = days.iter().map(|&day| Json::object([
+        ("day", Json::from(day as u64)),
+        ("results", Json::from(day_results(leaderboard, day).iter().map(|result| Json::object([
+            ("id", Json::from(result.member.id)),
+            ("name", Json::from(result.member.name.as_str())),
+            ("part_1", Json::from(result.part_1)),
+            ("part_2", Json::from(result.part_2)),
+            ("delta", Json::from(result.delta())),
+        ])).collect::<Vec<_>>())),
+    ])).collect::<Vec<_>>();
+
+    Json::object([("event", Json::from(leaderboard.event as u64)), ("members", Json::from(members)), ("days", Json::from(days))])
+}
+
+/// Prints the rankings and the times of each day, or of `--day`, as tables or JSON.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let leaderboard = parse_leaderboard(&read_leaderboard(args)?)?;
+    let days: Vec<u8> = match args.parsed_value("--day")? {
+        Some(day) => vec![day],
+        None => (1..=LAST_DAY).collect(),
+    };
+
+    match args.value("--format").unwrap_or("table") {
+        "table" => print_tables(&leaderboard, &days),
+        "json" => println!("{}", to_json(&leaderboard, &days).pretty()),
+        format => return Err(format!("Unknown format '{format}', expected 'table' or 'json'")),
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2024-12-01 05:00:00 UTC
+    const UNLOCK: u64 = 1733029200;
+
+    fn leaderboard() -> Leaderboard {
+        let star = |offset: u64| format!("{{\"get_star_ts\": {}, \"star_index\": 0}}", UNLOCK + offset);
+        let text = format!(r#"{{"event": 2024, "members": {{
+            "1": {{"id": 1, "name": "Ada", "completion_day_level": {{"1": {{"1": {}, "2": {}}}}}}},
+            "2": {{"id": 2, "name": "Bob", "completion_day_level": {{"1": {{"1": {}, "2": {}}}}}}},
+            "3": {{"id": 3, "name": null, "completion_day_level": {{"1": {{"1": {}}}}}}}
+        }}}}"#, star(100), star(300), star(50), star(400), star(200));
+        parse_leaderboard(&text).unwrap()
+    }
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = leaderboard();
+        assert_eq!(leaderboard.event, 2024);
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let anonymous = leaderboard.members.iter().find(|m| m.id == 3).unwrap();
+        assert_eq!(anonymous.name, "(anonymous user #3)");
+        assert_eq!(anonymous.stars, BTreeMap::from([((1, 1), UNLOCK + 200)]));
+        assert_eq!(anonymous.star_line(2), "+.");
+
+        assert!(parse_leaderboard(r#"{"event": 2024}"#).is_err());
+        assert!(parse_leaderboard(r#"{"event": 2024, "members": {"1": {"name": "Ada"}}}"#).is_err());
+        assert!(parse_leaderboard(r#"{"event": 2024, "members": {"1": {"id": 1, "completion_day_level": {"x": {}}}}}"#).is_err());
+    }
+
+    #[test]
+    fn scores_by_place() {
+        let leaderboard = leaderboard();
+        let ranks: Vec<(u64, u64)> = rankings(&leaderboard).iter().map(|r| (r.member.id, r.score)).collect();
+
+        /* Bob is first on part 1 and Ada on part 2: their tie is broken by the time of the last star */
+        assert_eq!(ranks, [(1, 3 + 2), (2, 2 + 3), (3, 1)]);
+    }
+
+    #[test]
+    fn day_results_by_delta() {
+        let leaderboard = leaderboard();
+        let results: Vec<(u64, u64, u64, u64)> = day_results(&leaderboard, 1).iter()
+                                                     .map(|r| (r.member.id, r.part_1, r.part_2, r.delta()))
+                                                     .collect();
+
+        assert_eq!(results, [(1, 100, 300, 200), (2, 50, 400, 350)]);
+        assert!(day_results(&leaderboard, 2).is_empty());
+        assert_eq!(DayResult { member: &leaderboard.members[0], part_1: 10, part_2: 5 }.delta(), 0);
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(unlock_time(2024, 1), UNLOCK);
+        assert_eq!(unlock_time(2024, 25) - UNLOCK, 24 * 86400);
+
+        assert_eq!(format_timestamp(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_timestamp(UNLOCK + 3661), "2024-12-01 06:01:01 UTC");
+        assert_eq!(format_timestamp(951782400), "2000-02-29 00:00:00 UTC");
+
+        assert_eq!(format_duration(59), "00:00:59");
+        assert_eq!(format_duration(86400 + 3600 + 61), "1d 01:01:01");
+    }
+
+    #[test]
+    fn quotes_session() {
+        assert_eq!(session_config("53616c"), "cookie = \"session=53616c\"\n");
+        assert_eq!(session_config(r#"a"b\c"#), "cookie = \"session=a\\\"b\\\\c\"\n");
+    }
+}
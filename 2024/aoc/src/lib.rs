@@ -0,0 +1,19 @@
+/* ADVENT OF CODE
+ * Runner of the solutions of all the days.
+ */
+pub mod bench;
+pub mod cache;
+pub mod cli;
+pub mod diff;
+pub mod examples;
+pub mod generate;
+pub mod info;
+pub mod inspect;
+pub mod leaderboard;
+pub mod registry;
+pub mod report;
+pub mod run;
+pub mod shrink;
+pub mod snapshot;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod tui;
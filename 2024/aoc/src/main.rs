@@ -0,0 +1,75 @@
+/* ADVENT OF CODE
+ * Runs the solutions of the puzzles.
+ *
+ * Usage: aoc run --day <N> ...    solves the parts of a day, or of every day with --all
+ *        aoc bench ...            benchmarks the solutions
+ *        aoc report ...           writes an overview of the calendar
+ *        aoc info ...             describes the puzzles
+ *        aoc gen ...              prints a random input
+ *        aoc diff ...             compares two implementations of a part
+ *        aoc shrink ...           minimises an input on which a solver fails
+ *        aoc leaderboard ...      analyses a private leaderboard
+ *        aoc tui                  dashboard of the parts in the terminal
+ *        aoc examples ...         extracts the examples from a saved puzzle page
+ *        aoc snapshot ...         compares the rendered worlds with their snapshots
+ *        aoc inspect ...          statistics of the input of a day
+ */
+use aoc::{bench, diff, examples, generate, info, inspect, leaderboard, report, run, shrink, snapshot, tui};
+use aoc_common::args::Args;
+use std::process::ExitCode;
+
+const VALUE_OPTIONS: &[&str] = &[
+    "--year", "--day", "--part", "--input", "--timeout", "--fps", "--frames-out", "--render",
+    "--runs", "--config", "--history", "--output", "--renders", "--tag", "--seed", "--impl", "--generated",
+    "--file", "--url", "--format", "--cpu-limit", "--memory-limit", "--from", "--block",
+];
+const FLAGS: &[&str] = &["--test", "--resume", "--animate", "--step", "--baseline", "--all", "--no-cache", "--all-inputs", "--sandbox", "--force",
+                         "--update", "--profile"];
+
+fn usage() -> String {
+    let commands = [run::USAGE, bench::USAGE, report::USAGE, info::USAGE, generate::USAGE, diff::USAGE, shrink::USAGE, leaderboard::USAGE, tui::USAGE,
+                    examples::USAGE, snapshot::USAGE, inspect::USAGE];
+    format!("Usage: {}", commands.join("\n       "))
+}
+
+fn main() -> ExitCode {
+    let args = match Args::from_env(VALUE_OPTIONS) {
+        Ok(a) => a,
+        Err(e) => {
+            println!("{e}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let unknown_flags = args.unknown_flags(FLAGS);
+    if !unknown_flags.is_empty() {
+        println!("Unknown option(s): {}\n{}", unknown_flags.join(", "), usage());
+        return ExitCode::FAILURE;
+    }
+
+    let result = match args.positional.first().map(|c| c.as_str()) {
+        Some("run") => run::command(&args),
+        Some("bench") => bench::command(&args),
+        Some("report") => report::command(&args),
+        Some("info") => info::command(&args),
+        Some("gen") => generate::command(&args),
+        Some("diff") => diff::command(&args),
+        Some("shrink") => shrink::command(&args),
+        Some("leaderboard") => leaderboard::command(&args),
+        Some("tui") => tui::command(),
+        Some("examples") => examples::command(&args),
+        Some("snapshot") => snapshot::command(&args),
+        Some("inspect") => inspect::command(&args),
+        Some("worker") => run::worker(&args),
+        _ => Err(usage()),
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            println!("{e}");
+            ExitCode::FAILURE
+        },
+    }
+}
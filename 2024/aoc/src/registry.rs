@@ -0,0 +1,153 @@
+/* Registry of the solutions of each day, used by the `aoc` command to find them, and of the
+ * metadata of their puzzles.
+ */
+use aoc_common::inspect::Inspector;
+use aoc_common::runner::Solver;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Name of the input file in `input_data`, without its extension
+    pub input: &'static str,
+    /// Name of the crate of the solver
+    pub krate: &'static str,
+    /// Name of the implementation, `main` for the one used by all the commands
+    pub implementation: &'static str,
+    pub solve: Solver,
+    /// Hash of the sources of the crate and of its dependencies, which versions its cached answers
+    pub source_hash: &'static str,
+    /// Statistics of the input of the day, shown by `aoc inspect`
+    pub inspect: Option<Inspector>,
+}
+
+/// Solution solved by the crate `krate`, whose sources are hashed by `build.rs`. The main
+/// implementation is its `solve` function, the other ones are named after their function. `inspect`
+/// names the statistics of the input of the day.
+macro_rules! solution {
+    ($year:expr, $day:expr, $part:expr, $input:expr, $krate:ident) => {
+        solution!($year, $day, $part, $input, $krate, solve, "main", None)
+    };
+    ($year:expr, $day:expr, $part:expr, $input:expr, $krate:ident, inspect = $inspect:path) => {
+        solution!($year, $day, $part, $input, $krate, solve, "main", Some($inspect))
+    };
+    ($year:expr, $day:expr, $part:expr, $input:expr, $krate:ident, $solve:ident, $implementation:expr) => {
+        solution!($year, $day, $part, $input, $krate, $solve, $implementation, None)
+    };
+    ($year:expr, $day:expr, $part:expr, $input:expr, $krate:ident, $solve:ident, $implementation:expr, $inspect:expr) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            input: $input,
+            krate: stringify!($krate),
+            implementation: $implementation,
+            solve: $krate::$solve,
+            source_hash: env!(concat!("AOC_SOURCE_HASH_", stringify!($krate))),
+            inspect: $inspect,
+        }
+    };
+}
+
+impl Solution {
+    pub fn puzzle(&self) -> Option<&'static Puzzle> {
+        puzzle(self.year, self.day)
+    }
+}
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    /// Kind of problem and techniques of the solutions, e.g. `grid`, `simulation` or `graph`
+    pub tags: &'static [&'static str],
+}
+
+impl Puzzle {
+    /// Canonical URL of a part of the puzzle, the second part being an anchor of the page.
+    pub fn url(&self, part: u8) -> String {
+        match part {
+            1 => format!("https://adventofcode.com/{}/day/{}", self.year, self.day),
+            _ => format!("https://adventofcode.com/{}/day/{}#part{part}", self.year, self.day),
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.contains(&tag)
+    }
+}
+
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle { year: 2024, day: 1, title: "Historian Hysteria", tags: &["lists", "sorting"] },
+    Puzzle { year: 2024, day: 2, title: "Red-Nosed Reports", tags: &["lists"] },
+    Puzzle { year: 2024, day: 3, title: "Mull It Over", tags: &["parsing"] },
+    Puzzle { year: 2024, day: 4, title: "Ceres Search", tags: &["grid"] },
+    Puzzle { year: 2024, day: 5, title: "Print Queue", tags: &["graph", "sorting"] },
+    Puzzle { year: 2024, day: 6, title: "Guard Gallivant", tags: &["grid", "simulation"] },
+    Puzzle { year: 2024, day: 7, title: "Bridge Repair", tags: &["search"] },
+    Puzzle { year: 2024, day: 8, title: "Resonant Collinearity", tags: &["grid", "geometry"] },
+    Puzzle { year: 2024, day: 9, title: "Disk Fragmenter", tags: &["simulation"] },
+    Puzzle { year: 2024, day: 10, title: "Hoof It", tags: &["grid", "graph", "search"] },
+];
+
+pub fn puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES.iter().find(|p| p.year == year && p.day == day)
+}
+
+/// Tags of all the puzzles, sorted
+pub fn tags() -> Vec<&'static str> {
+    let mut tags: Vec<&str> = PUZZLES.iter().flat_map(|p| p.tags.iter().copied()).collect();
+    tags.sort_unstable();
+    tags.dedup();
+    tags
+}
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(2024, 1, 1, "aoc_01", aoc_01),
+    solution!(2024, 1, 2, "aoc_01", aoc_01_part2),
+    solution!(2024, 2, 1, "aoc_02", aoc_02),
+    solution!(2024, 2, 2, "aoc_02", aoc_02_part2),
+    solution!(2024, 3, 1, "aoc_03", aoc_03),
+    solution!(2024, 3, 2, "aoc_03", aoc_03_part2),
+    solution!(2024, 4, 1, "aoc_04", aoc_04),
+    solution!(2024, 4, 2, "aoc_04", aoc_04_part2),
+    solution!(2024, 5, 1, "aoc_05", aoc_05),
+    solution!(2024, 5, 2, "aoc_05", aoc_05_part2),
+    solution!(2024, 6, 1, "aoc_06", aoc_06),
+    solution!(2024, 6, 2, "aoc_06", aoc_06_part2),
+    solution!(2024, 7, 1, "aoc_07", aoc_07, inspect = aoc_07::inspect),
+    solution!(2024, 7, 2, "aoc_07", aoc_07_part2, inspect = aoc_07::inspect),
+    solution!(2024, 8, 1, "aoc_08", aoc_08, inspect = aoc_08::inspect),
+    solution!(2024, 8, 2, "aoc_08", aoc_08_part2, inspect = aoc_08::inspect),
+    solution!(2024, 9, 1, "aoc_09", aoc_09, inspect = aoc_09::inspect),
+    solution!(2024, 9, 2, "aoc_09", aoc_09_part2, inspect = aoc_09::inspect),
+    solution!(2024, 10, 1, "aoc_10", aoc_10, inspect = aoc_10::inspect),
+    solution!(2024, 10, 2, "aoc_10", aoc_10_part2, inspect = aoc_10::inspect),
+];
+
+/// Other implementations of some parts, compared to the main ones by `aoc diff`.
+pub const ALTERNATIVES: &[Solution] = &[
+    solution!(2024, 1, 2, "aoc_01", aoc_01_part2, solve_counting, "counting"),
+];
+
+/// Main implementation of a part
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+/// Solutions of both parts of a day
+pub fn of_day(year: u16, day: u8) -> Vec<&'static Solution> {
+    SOLUTIONS.iter().filter(|s| s.year == year && s.day == day).collect()
+}
+
+pub fn of_year(year: u16) -> Vec<&'static Solution> {
+    SOLUTIONS.iter().filter(|s| s.year == year).collect()
+}
+
+/// Every implementation of a part, the main one first
+pub fn implementations(year: u16, day: u8, part: u8) -> Vec<&'static Solution> {
+    SOLUTIONS.iter()
+             .chain(ALTERNATIVES)
+             .filter(|s| s.year == year && s.day == day && s.part == part)
+             .collect()
+}
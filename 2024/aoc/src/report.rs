@@ -0,0 +1,277 @@
+/* `aoc report`: overview of the calendar, as a Markdown table or a standalone HTML page.
+ *
+ * The puzzles are described by the registry. Nothing is solved, the report gathers what the other
+ * commands left:
+ * - the answers of the answer cache which are still valid for the input and the solver,
+ * - the expected answers of `input_data/answers.txt`, written `2024/6/1 = 41`, or of
+ *   `input_data/answers_test.txt` for the examples with `--test`,
+ * - the median run time of the last baseline of `aoc bench` on the same input,
+ * - the visualisations of the render directory, named after the part, e.g. `2024_06_1.gif`.
+ */
+use crate::bench::{self, HistoryEntry};
+use crate::cache::{self, AnswerCache};
+use crate::cli;
+use crate::registry::{self, Solution};
+use aoc_common::answer::Answer;
+use aoc_common::args::Args;
+use aoc_common::input;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::time::Duration;
+
+pub const USAGE: &str = "aoc report [--year <Y>] [--test] [--output <FILE.md|FILE.html>]... [--renders <DIR>]";
+
+const RENDER_DIR: &str = "../renders";
+const RENDER_EXTENSIONS: [&str; 2] = ["gif", "png"];
+
+#[derive(Clone, Debug)]
+enum Status {
+    NoInput,
+    NotRun,
+    Solved(Answer),
+}
+
+/// Line of the report, for a part.
+struct Row {
+    solution: &'static Solution,
+    status: Status,
+    expected: Option<String>,
+    median: Option<Duration>,
+    visualisation: Option<PathBuf>,
+}
+
+impl Row {
+    fn status(&self) -> &str {
+        match self.status {
+            Status::NoInput => "no input",
+            Status::NotRun => "not run",
+            Status::Solved(_) => "solved",
+        }
+    }
+
+    fn answer(&self) -> String {
+        match &self.status {
+            Status::Solved(answer) => answer.to_string(),
+            _ => String::new(),
+        }
+    }
+
+    fn is_verified(&self) -> bool {
+        matches!((&self.status, &self.expected), (Status::Solved(answer), Some(expected)) if answer.matches(expected))
+    }
+
+    fn verified(&self) -> String {
+        match (&self.status, &self.expected) {
+            _ if self.is_verified() => "✓".to_string(),
+            (Status::Solved(_), Some(expected)) => format!("✗ expected {expected}"),
+            (_, Some(_)) => "pending".to_string(),
+            (_, None) => "—".to_string(),
+        }
+    }
+
+    fn median(&self) -> String {
+        self.median.map(|m| format!("{m:.2?}")).unwrap_or_default()
+    }
+}
+
+/// Reads the expected answers, a missing file giving none.
+pub fn load_expected(path: &Path) -> Result<HashMap<(u16, u8, u8), String>, String> {
+    let mut expected = HashMap::new();
+    if !path.is_file() {
+        return Ok(expected);
+    }
+
+    let text = fs::read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?;
+    for (idx, line) in text.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let error = || format!("Invalid answers '{}': line {}, expected '<year>/<day>/<part> = <answer>'", path.display(), idx + 1);
+        let (key, answer) = line.split_once('=').ok_or_else(error)?;
+        let numbers: Vec<&str> = key.trim().split('/').collect();
+        let [year, day, part] = numbers[..] else {
+            return Err(error());
+        };
+        let key = (year.parse().map_err(|_| error())?, day.parse().map_err(|_| error())?, part.parse().map_err(|_| error())?);
+        expected.insert(key, answer.trim().to_string());
+    }
+
+    Ok(expected)
+}
+
+fn visualisation(dir: &Path, solution: &Solution) -> Option<PathBuf> {
+    RENDER_EXTENSIONS.iter()
+                     .map(|ext| dir.join(format!("{}_{:02}_{}.{ext}", solution.year, solution.day, solution.part)))
+                     .find(|p| p.is_file())
+}
+
+/// Path of `target` as seen from the directory `base`, so that the links of a report work
+/// wherever it is written. `target` is kept as is when one of them does not exist.
+fn relative_path(target: &Path, base: &Path) -> PathBuf {
+    let (Ok(target_abs), Ok(base_abs)) = (target.canonicalize(), base.canonicalize()) else {
+        return target.to_path_buf();
+    };
+
+    let target_parts: Vec<Component> = target_abs.components().collect();
+    let base_parts: Vec<Component> = base_abs.components().collect();
+    let common = target_parts.iter().zip(&base_parts).take_while(|(a, b)| a == b).count();
+
+    let mut result = PathBuf::new();
+    for _ in common..base_parts.len() {
+        result.push("..");
+    }
+    result.extend(&target_parts[common..]);
+    result
+}
+
+fn collect_rows(args: &Args) -> Result<Vec<Row>, String> {
+    let cache = AnswerCache::load(Path::new(cache::CACHE_FILE))?;
+    let expected = load_expected(&cli::answers_file(args.flag("--test")))?;
+    let history: Vec<HistoryEntry> = bench::load_history(Path::new(bench::HISTORY_FILE))?;
+    let render_dir = PathBuf::from(args.value("--renders").unwrap_or(RENDER_DIR));
+    let year = args.parsed_value("--year")?.unwrap_or(cli::DEFAULT_YEAR);
+
+    let rows = registry::of_year(year).into_iter().map(|solution| {
+        let input = fs::read_to_string(cli::input_file(solution, args.flag("--test"))).ok();
+        let status = match &input {
+            None => Status::NoInput,
+            Some(input) => cache.get(solution, input).map(Status::Solved).unwrap_or(Status::NotRun),
+        };
+
+        Row {
+            solution,
+            status,
+            expected: expected.get(&(solution.year, solution.day, solution.part)).cloned(),
+            median: input.and_then(|i| bench::baseline(&history, solution.year, solution.day, solution.part, &input::hash(&i)))
+                         .map(|s| Duration::from_secs_f64(s.median)),
+            visualisation: visualisation(&render_dir, solution),
+        }
+    }).collect();
+
+    Ok(rows)
+}
+
+fn summary(rows: &[Row]) -> String {
+    let solved = rows.iter().filter(|r| matches!(r.status, Status::Solved(_))).count();
+    let verified = rows.iter().filter(|r| r.is_verified()).count();
+    format!("{solved} of {} parts solved, {verified} verified.", rows.len())
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn markdown(rows: &[Row], year: u16, report_dir: &Path) -> String {
+    let mut text = format!("# Advent of Code {year}\n\n{}\n\n", summary(rows));
+    text += "| Day | Part | Puzzle | Status | Answer | Verified | Median | Visualisation |\n";
+    text += "|----:|-----:|--------|--------|--------|----------|-------:|---------------|\n";
+
+    for row in rows {
+        let day = row.solution.day;
+        let puzzle = match row.solution.puzzle() {
+            Some(puzzle) => format!("[{}]({})", markdown_cell(puzzle.title), puzzle.url(row.solution.part)),
+            None => format!("Day {day}"),
+        };
+        let visualisation = match &row.visualisation {
+            Some(path) => format!("[{}]({})", path.extension().unwrap_or_default().to_string_lossy(),
+                                  relative_path(path, report_dir).display()),
+            None => String::new(),
+        };
+
+        text += &format!("| {day} | {} | {puzzle} | {} | {} | {} | {} | {visualisation} |\n", row.solution.part,
+                         row.status(), markdown_cell(&row.answer()), markdown_cell(&row.verified()), row.median());
+    }
+
+    text
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn html(rows: &[Row], year: u16, report_dir: &Path) -> String {
+    let mut body = String::new();
+
+    for row in rows {
+        let day = row.solution.day;
+        let puzzle = match row.solution.puzzle() {
+            Some(puzzle) => format!("<a href=\"{}\">{}</a>", puzzle.url(row.solution.part), html_escape(puzzle.title)),
+            None => format!("Day {day}"),
+        };
+        let visualisation = match &row.visualisation {
+            Some(path) => {
+                let link = html_escape(&relative_path(path, report_dir).display().to_string());
+                format!("<a href=\"{link}\"><img src=\"{link}\" alt=\"Day {day} part {}\"></a>", row.solution.part)
+            },
+            None => String::new(),
+        };
+
+        body += &format!("      <tr class=\"{}\"><td>{day}</td><td>{}</td><td>{puzzle}</td><td>{}</td><td><pre>{}</pre></td>\
+                          <td>{}</td><td>{}</td><td>{visualisation}</td></tr>\n",
+                         row.status().replace(' ', "-"), row.solution.part, row.status(), html_escape(&row.answer()),
+                         html_escape(&row.verified()), row.median());
+    }
+
+    format!(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>Advent of Code {year}</title>
+  <style>
+    body {{ font-family: sans-serif; margin: 2em; }}
+    table {{ border-collapse: collapse; }}
+    th, td {{ border: 1px solid #ccc; padding: 0.3em 0.6em; vertical-align: top; }}
+    th {{ background: #eee; }}
+    pre {{ margin: 0; }}
+    img {{ max-width: 160px; image-rendering: pixelated; }}
+    tr.solved td:nth-child(4) {{ color: #080; }}
+    tr.no-input td:nth-child(4) {{ color: #888; }}
+  </style>
+</head>
+<body>
+  <h1>Advent of Code {year}</h1>
+  <p>{}</p>
+  <table>
+    <thead>
+      <tr><th>Day</th><th>Part</th><th>Puzzle</th><th>Status</th><th>Answer</th><th>Verified</th><th>Median</th><th>Visualisation</th></tr>
+    </thead>
+    <tbody>
+{body}    </tbody>
+  </table>
+</body>
+</html>
+"#, summary(rows))
+}
+
+/// Writes the report to the files of `--output`, in the format given by their extension, or
+/// prints it in Markdown.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let rows = collect_rows(args)?;
+    let year = args.parsed_value("--year")?.unwrap_or(cli::DEFAULT_YEAR);
+
+    let outputs = args.values("--output");
+    if outputs.is_empty() {
+        print!("{}", markdown(&rows, year, Path::new(".")));
+        return Ok(true);
+    }
+
+    for output in outputs {
+        let path = Path::new(output);
+        let report_dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        let text = match path.extension().map(|e| e.to_string_lossy().to_lowercase()).as_deref() {
+            Some("md") => markdown(&rows, year, report_dir),
+            Some("html") => html(&rows, year, report_dir),
+            _ => return Err(format!("Unknown report format for '{output}', expected .md or .html")),
+        };
+
+        fs::write(path, text).map_err(|e| format!("Could not write '{output}': {e}"))?;
+        println!("Report written to '{output}'");
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,257 @@
+/* `aoc run`: solves the selected parts and prints their answers.
+ *
+ * The answers are cached, see `cache`. The runs recording an animation or profiled are never cached.
+ * With `--sandbox`, or a CPU or memory limit, each part runs in a child process, see
+ * `aoc_common::sandbox`.
+ * With `--all-inputs`, every input of the selected days is solved, e.g. the personal inputs of
+ * several people in subfolders of the input directory, and the answers are tabulated.
+ */
+use crate::cache::{self, AnswerCache};
+use crate::cli;
+use crate::diff;
+use crate::registry::Solution;
+use aoc_common::animation::PlaybackOptions;
+use aoc_common::args::Args;
+use aoc_common::checkpoint;
+use aoc_common::input;
+use aoc_common::runner::{self, Outcome, PartReport, RenderOptions, RunOptions};
+use aoc_common::sandbox::{self, Limits};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+pub const USAGE: &str = "aoc run (--day <N> [--part <P>] | --all | --tag <TAG>...) [--year <Y>] [--input <FILE|-> | --test] [--timeout <DURATION>]
+                [--resume] [--no-cache] [--animate] [--step] [--fps <N>] [--frames-out <FILE>] [--render <FILE.gif|FILE.png>]
+                [--profile] [--sandbox] [--cpu-limit <DURATION>] [--memory-limit <SIZE>]
+       aoc run (--day <N> [--part <P>] | --all | --tag <TAG>...) --all-inputs [--year <Y>] [--timeout <DURATION>] [--no-cache]
+                [--sandbox] [--cpu-limit <DURATION>] [--memory-limit <SIZE>]";
+
+/// Returns whether all the parts were solved.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let solutions = cli::selected_solutions(args, !args.flag("--all"))?;
+    let playback = PlaybackOptions::from_args(args)?;
+    let render = RenderOptions::from_args(args)?;
+    let options = RunOptions {
+        timeout: args.duration("--timeout")?,
+        resume: args.flag("--resume"),
+        record_frames: playback.is_some() || render.is_some(),
+        profile: args.flag("--profile"),
+        ..RunOptions::default()
+    };
+    let limits = Limits::from_args(args)?;
+    let sandbox = (args.flag("--sandbox") || limits.cpu.is_some() || limits.memory.is_some()).then_some(&limits);
+    if sandbox.is_some() && (options.record_frames || options.resume || options.profile) {
+        return Err("The sandbox cannot be combined with '--resume', '--profile' nor an animation".to_string());
+    }
+    /* The cached answers have neither frames nor profile */
+    let mut cache = match args.flag("--no-cache") || options.record_frames || options.profile {
+        true => None,
+        false => Some(AnswerCache::load(Path::new(cache::CACHE_FILE))?),
+    };
+    if args.flag("--all-inputs") {
+        if args.value("--input").is_some() || args.flag("--test") || options.record_frames || options.resume || options.profile {
+            return Err("'--all-inputs' cannot be combined with '--input', '--test', '--resume', '--profile' or an animation".to_string());
+        }
+        let all_solved = run_all_inputs(&solutions, &options, sandbox, cache.as_mut());
+        if let Some(Err(e)) = cache.map(|c| c.save()) {
+            println!("{e}");
+        }
+        return Ok(all_solved);
+    }
+    cli::check_piped_input(&solutions, args)?;
+    let mut all_solved = true;
+
+    for solution in solutions {
+        let label = cli::label(solution);
+        let input = match cli::input_source(solution, args).read() {
+            Ok(i) => i,
+            Err(e) => {
+                println!("{label}: skipped: {e}");
+                all_solved = false;
+                continue;
+            },
+        };
+        if let Some(answer) = cache.as_ref().and_then(|c| c.get(solution, &input)) {
+            println!("{label}: {} (cached)", Outcome::Solved(answer));
+            continue;
+        }
+
+        let checkpoint = checkpoint::checkpoint_file(solution.krate);
+        let options = RunOptions { progress_label: Some(label.clone()), checkpoint: Some(checkpoint), ..options.clone() };
+        let report = solve(solution, &input, &options, sandbox);
+        /* The diagnostics of the invalid inputs span several lines, the duration goes before them */
+        match report.outcome.to_string() {
+            outcome if outcome.contains('\n') => println!("{label} ({:?}): {outcome}", report.elapsed),
+            outcome => println!("{label}: {outcome} ({:?})", report.elapsed),
+        }
+        runner::print_allocations(&report);
+        runner::print_profile(&report);
+
+        if let Some(render) = &render {
+            runner::render_frames(&report.frames, render);
+        }
+        if let Some(playback) = &playback {
+            runner::play_frames(&report.frames, playback);
+        }
+
+        if let (Some(cache), Outcome::Solved(answer)) = (&mut cache, &report.outcome) {
+            cache.insert(solution, &input, answer);
+        }
+        all_solved &= matches!(report.outcome, Outcome::Solved(_));
+    }
+
+    if let Some(Err(e)) = cache.map(|c| c.save()) {
+        println!("{e}");
+    }
+
+    Ok(all_solved)
+}
+
+/// Solves a part in this process, or in a child `aoc worker` with the limits of the sandbox.
+fn solve(solution: &Solution, input: &str, options: &RunOptions, sandbox: Option<&Limits>) -> PartReport {
+    let Some(limits) = sandbox else {
+        return runner::run_part(solution.solve, input, options);
+    };
+
+    match worker_command(solution) {
+        Ok(command) => sandbox::run_in_child(command, input, limits),
+        Err(e) => PartReport {
+            outcome: Outcome::Failed(e),
+            elapsed: Duration::ZERO,
+            frames: Vec::new(),
+            allocations: None,
+            alloc_phases: Vec::new(),
+            profile: Vec::new(),
+        },
+    }
+}
+
+/// Command of the child `aoc worker` solving the implementation of a part, see `sandbox`.
+pub fn worker_command(solution: &Solution) -> Result<Command, String> {
+    let exe = std::env::current_exe().map_err(|e| format!("Could not find the binary to run in the sandbox: {e}"))?;
+    let mut command = Command::new(exe);
+    command.args(["worker", "--year", &solution.year.to_string(), "--day", &solution.day.to_string(),
+                  "--part", &solution.part.to_string(), "--impl", solution.implementation]);
+    Ok(command)
+}
+
+/// Child of the sandbox: solves the implementation `--impl` of the part of `--day` and `--part` on
+/// the standard input.
+pub fn worker(args: &Args) -> Result<bool, String> {
+    if !sandbox::is_worker() {
+        return Err("'aoc worker' is started by 'aoc run --sandbox'".to_string());
+    }
+    let [solution] = cli::selected_solutions(args, true)?[..] else {
+        return Err("Expected a single part, with '--day <N> --part <P>'".to_string());
+    };
+    let solution = match args.value("--impl") {
+        Some(name) => diff::implementation(solution.year, solution.day, solution.part, name)
+                          .ok_or(format!("Unknown implementation '{name}' of {}", cli::label(solution)))?,
+        None => solution,
+    };
+
+    sandbox::run_worker(solution.solve, &input::read_stdin()?)?;
+    Ok(true)
+}
+
+/// Answer of a part on an input, in the table of `--all-inputs`.
+struct InputRow {
+    input: String,
+    part: u8,
+    outcome: Outcome,
+    /// Run time, `None` for a cached answer
+    elapsed: Option<Duration>,
+}
+
+impl InputRow {
+    fn answer(&self) -> String {
+        match &self.outcome {
+            Outcome::Solved(answer) if answer.is_multiline() => format!("({} lines)", answer.to_string().lines().count()),
+            Outcome::Solved(answer) => answer.to_string(),
+            Outcome::Panicked(e) => format!("PANIC: {e}"),
+            Outcome::ExceededMemory(_) => format!("OUT OF MEMORY: {}", self.outcome),
+            outcome => outcome.to_string().lines().next().unwrap_or_default().to_string(),
+        }
+    }
+
+    fn elapsed(&self) -> String {
+        self.elapsed.map(|e| format!("{e:.2?}")).unwrap_or("cached".to_string())
+    }
+}
+
+/// Solves the parts on every input of their day and prints a table per day. Returns whether all
+/// the parts were solved on all the inputs.
+fn run_all_inputs(solutions: &[&Solution], options: &RunOptions, sandbox: Option<&Limits>, mut cache: Option<&mut AnswerCache>) -> bool {
+    let mut all_solved = true;
+    let mut days: Vec<(u16, u8)> = solutions.iter().map(|s| (s.year, s.day)).collect();
+    days.dedup();
+
+    for (year, day) in days {
+        let parts: Vec<&Solution> = solutions.iter().copied().filter(|s| (s.year, s.day) == (year, day)).collect();
+        let files = match cli::all_input_files(parts[0]) {
+            Ok(files) if files.is_empty() => {
+                println!("{year} day {day:02}: skipped: no input found in '{}'", cli::INPUT_DIR);
+                all_solved = false;
+                continue;
+            },
+            Ok(files) => files,
+            Err(e) => {
+                println!("{year} day {day:02}: skipped: {e}");
+                all_solved = false;
+                continue;
+            },
+        };
+
+        let mut rows = Vec::new();
+        for file in &files {
+            let name = file.strip_prefix(cli::INPUT_DIR).unwrap_or(file).display().to_string();
+            let input = match fs::read_to_string(file) {
+                Ok(input) => input,
+                Err(e) => {
+                    println!("{year} day {day:02}: skipped '{name}': {e}");
+                    all_solved = false;
+                    continue;
+                },
+            };
+
+            for solution in &parts {
+                let row = match cache.as_ref().and_then(|c| c.get(solution, &input)) {
+                    Some(answer) => InputRow { input: name.clone(), part: solution.part, outcome: Outcome::Solved(answer), elapsed: None },
+                    None => {
+                        let report = solve(solution, &input, options, sandbox);
+                        if let (Some(cache), Outcome::Solved(answer)) = (cache.as_mut(), &report.outcome) {
+                            cache.insert(solution, &input, answer);
+                        }
+                        InputRow { input: name.clone(), part: solution.part, outcome: report.outcome, elapsed: Some(report.elapsed) }
+                    },
+                };
+                all_solved &= matches!(row.outcome, Outcome::Solved(_));
+                rows.push(row);
+            }
+        }
+
+        print_input_table(year, day, &rows);
+    }
+
+    all_solved
+}
+
+fn print_input_table(year: u16, day: u8, rows: &[InputRow]) {
+    let input_width = rows.iter().map(|r| r.input.len()).chain(["Input".len()]).max().unwrap_or_default();
+    let answer_width = rows.iter().map(|r| r.answer().chars().count()).chain(["Answer".len()]).max().unwrap_or_default();
+
+    println!("{year} day {day:02}:");
+    println!("  {:<input_width$}  Part  {:<answer_width$}  Time", "Input", "Answer");
+    for row in rows {
+        println!("  {:<input_width$}  {:>4}  {:<answer_width$}  {}", row.input, row.part, row.answer(), row.elapsed());
+    }
+
+    let panics: Vec<String> = rows.iter()
+                                  .filter(|r| matches!(r.outcome, Outcome::Panicked(_)))
+                                  .map(|r| format!("{} part {}", r.input, r.part))
+                                  .collect();
+    if !panics.is_empty() {
+        println!("  {} panic(s): {}", panics.len(), panics.join(", "));
+    }
+}
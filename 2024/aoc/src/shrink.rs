@@ -0,0 +1,270 @@
+/* `aoc shrink`: minimises an input on which a solver fails, with delta debugging.
+ *
+ * The failure is a panic, a timeout or an error of the solver, or with two `--impl` a divergence as
+ * found by `aoc diff`. Chunks of lines, then of grid columns, then of characters of each line are
+ * removed, smaller and smaller, as long as the failure still reproduces. An error only reproduces
+ * with the same message, so that the shrinking does not drift to any invalid input, but wherever
+ * it is found: the positions of the message and the annotated line are ignored.
+ *
+ * Each attempt runs in a child process of the sandbox, see `aoc_common::sandbox`, so that the
+ * solvers sent in an endless loop are killed at the timeout instead of piling up.
+ */
+use crate::cli::{self, INPUT_DIR};
+use crate::diff;
+use crate::registry::Solution;
+use crate::run;
+use aoc_common::args::Args;
+use aoc_common::runner::Outcome;
+use aoc_common::sandbox::{self, Limits};
+use std::fs;
+use std::io;
+use std::mem;
+use std::time::Duration;
+
+pub const USAGE: &str = "aoc shrink --day <N> --part <P> [--year <Y>] [--input <FILE|-> | --test] [--impl <A> --impl <B>] [--timeout <DURATION>]
+                [--cpu-limit <DURATION>] [--memory-limit <SIZE>] [--output <FILE>]";
+
+/// Timeout of each run, as removing characters may send a solver in an endless loop.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Failure to keep while shrinking.
+enum Failure<'a> {
+    Outcome(&'a Solution, Outcome),
+    Divergence(&'a Solution, &'a Solution),
+}
+
+impl Failure<'_> {
+    fn reproduces(&self, input: &str, limits: &Limits) -> bool {
+        match self {
+            Failure::Outcome(solution, expected) => {
+                match (solve(solution, input, limits), expected) {
+                    (Outcome::Failed(e), Outcome::Failed(expected)) => error_kind(&e) == error_kind(expected),
+                    (Outcome::Panicked(_), Outcome::Panicked(_))
+                    | (Outcome::TimedOut(_), Outcome::TimedOut(_))
+                    | (Outcome::ExceededMemory(_), Outcome::ExceededMemory(_)) => true,
+                    _ => false,
+                }
+            },
+            Failure::Divergence(a, b) => !diff::agree(&solve(a, input, limits), &solve(b, input, limits)),
+        }
+    }
+}
+
+/// Solves the part in a child process, whose logs and panic messages are dropped.
+fn solve(solution: &Solution, input: &str, limits: &Limits) -> Outcome {
+    match run::worker_command(solution) {
+        Ok(command) => sandbox::run_in_child_with_logs(command, input, limits, io::sink()).outcome,
+        Err(e) => Outcome::Failed(e),
+    }
+}
+
+/// First line of an error with its positions masked: `Invalid map: line _, column _: second guard
+/// '^', the first one is at line _, column _`.
+fn error_kind(error: &str) -> String {
+    let line = error.lines().next().unwrap_or_default();
+    let mut kind = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while !rest.is_empty() {
+        let keyword = ["line ", "column "].into_iter().find(|k| rest.starts_with(k));
+        match keyword.map(|k| (k, rest[k.len()..].trim_start_matches(|c: char| c.is_ascii_digit()))) {
+            Some((keyword, after)) if after.len() < rest.len() - keyword.len() => {
+                kind.push_str(keyword);
+                kind.push('_');
+                rest = after;
+            },
+            _ => {
+                let c = rest.chars().next().unwrap_or_default();
+                kind.push(c);
+                rest = &rest[c.len_utf8()..];
+            },
+        }
+    }
+
+    kind
+}
+
+/// Removes chunks of `items`, halving their size when none can be removed, as long as `fails`.
+/// The items left are 1-minimal: removing any of them makes the failure disappear.
+fn ddmin<T: Clone>(mut items: Vec<T>, mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+
+    while !items.is_empty() {
+        let chunk_len = items.len().div_ceil(chunks);
+        let reduced = (0..items.len()).step_by(chunk_len).find_map(|start| {
+            let mut complement = items[..start].to_vec();
+            complement.extend_from_slice(&items[(start + chunk_len).min(items.len())..]);
+            fails(&complement).then_some(complement)
+        });
+
+        match reduced {
+            Some(complement) => {
+                items = complement;
+                chunks = (chunks - 1).max(2);
+            },
+            None if chunk_len == 1 => break,
+            None => chunks = (chunks * 2).min(items.len()),
+        }
+    }
+
+    items
+}
+
+/// Input split in lines, rebuilt with the final newline of the original.
+struct Lines {
+    lines: Vec<String>,
+    final_newline: bool,
+}
+
+impl Lines {
+    fn new(input: &str) -> Self {
+        Self { lines: input.lines().map(str::to_string).collect(), final_newline: input.ends_with('\n') }
+    }
+
+    fn text(&self, lines: &[String]) -> String {
+        let text = lines.join("\n");
+        if self.final_newline && !text.is_empty() { text + "\n" } else { text }
+    }
+
+    fn is_grid(&self) -> bool {
+        self.lines.len() > 1 && self.lines.iter().all(|l| l.chars().count() == self.lines[0].chars().count())
+    }
+
+    /// Removes lines, then columns when the lines are a grid, then characters of each line.
+    /// Returns whether the input got smaller.
+    fn shrink(&mut self, fails: &mut impl FnMut(&str) -> bool) -> bool {
+        let before = self.text(&self.lines).len();
+
+        let lines = mem::take(&mut self.lines);
+        self.lines = ddmin(lines, |lines| fails(&self.text(lines)));
+
+        if self.is_grid() {
+            let grid: Vec<Vec<char>> = self.lines.iter().map(|l| l.chars().collect()).collect();
+            let select = |columns: &[usize]| -> Vec<String> {
+                grid.iter().map(|row| columns.iter().map(|&c| row[c]).collect()).collect()
+            };
+            let columns = ddmin((0..grid[0].len()).collect(), |columns| fails(&self.text(&select(columns))));
+            self.lines = select(&columns);
+        }
+
+        for idx in 0..self.lines.len() {
+            let chars: Vec<char> = self.lines[idx].chars().collect();
+            let chars = ddmin(chars, |chars| {
+                let mut lines = self.lines.clone();
+                lines[idx] = chars.iter().collect();
+                fails(&self.text(&lines))
+            });
+            self.lines[idx] = chars.into_iter().collect();
+        }
+
+        self.text(&self.lines).len() < before
+    }
+}
+
+/// Shrinks the input of `--part` until removing anything makes the failure disappear, then writes
+/// it to `--output`, by default next to the inputs, and prints it.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let solutions = cli::selected_solutions(args, true)?;
+    let [solution] = solutions[..] else {
+        return Err("Expected a single part to shrink, with '--day <N> --part <P>'".to_string());
+    };
+    let limits = Limits::from_args(args)?;
+    let limits = Limits { wall: Some(limits.wall.unwrap_or(DEFAULT_TIMEOUT)), ..limits };
+    let input = cli::input_source(solution, args).read()?;
+
+    let failure = match args.values("--impl")[..] {
+        [] => match solve(solution, &input, &limits) {
+            Outcome::Solved(answer) => return Err(format!("{} solves the input: {answer}", cli::label(solution))),
+            outcome => Failure::Outcome(solution, outcome),
+        },
+        [name_a, name_b] => {
+            let find = |name| diff::implementation(solution.year, solution.day, solution.part, name)
+                .ok_or(format!("Unknown implementation '{name}' of {}", cli::label(solution)));
+            Failure::Divergence(find(name_a)?, find(name_b)?)
+        },
+        _ => return Err("Expected no implementation or two, with '--impl <A> --impl <B>'".to_string()),
+    };
+    if !failure.reproduces(&input, &limits) {
+        return Err(format!("The failure of {} does not reproduce on its input", cli::label(solution)));
+    }
+
+    let mut fails = |candidate: &str| failure.reproduces(candidate, &limits);
+    let mut lines = Lines::new(&input);
+    while lines.shrink(&mut fails) {}
+    let shrunk = lines.text(&lines.lines);
+
+    let output = args.value("--output").map(str::to_string).unwrap_or(format!(
+        "{INPUT_DIR}/shrink_{}_{:02}_{}.txt", solution.year, solution.day, solution.part));
+    fs::write(&output, &shrunk).map_err(|e| format!("Could not write '{output}': {e}"))?;
+
+    let count = |text: &str| (text.lines().count(), text.len());
+    let ((lines_before, bytes_before), (lines_after, bytes_after)) = (count(&input), count(&shrunk));
+    println!("{}: shrunk from {lines_before} lines ({bytes_before} bytes) to {lines_after} lines ({bytes_after} bytes), \
+              written to '{output}'", cli::label(solution));
+    print!("{shrunk}");
+
+    Ok(true)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shrinks `input` as long as `fails` holds.
+    fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+        let mut lines = Lines::new(input);
+        while lines.shrink(&mut |candidate: &str| fails(candidate)) {}
+        lines.text(&lines.lines)
+    }
+
+    #[test]
+    fn ddmin_keeps_the_failing_items() {
+        let items: Vec<u32> = (0..20).collect();
+
+        assert_eq!(ddmin(items.clone(), |items| items.contains(&3) && items.contains(&17)), [3, 17]);
+        assert_eq!(ddmin(items.clone(), |items| items.iter().sum::<u32>() >= 30), [18, 19]);
+        assert_eq!(ddmin(items.clone(), |_| true), Vec::<u32>::new());
+        assert_eq!(ddmin(items, |items| items.len() == 20), (0..20).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn removes_lines() {
+        let input = "a\nb\nfail\nc\nd\nagain\ne\n";
+
+        assert_eq!(shrink(input, |text| text.contains("fail") && text.contains("again")), "fail\nagain\n");
+        assert_eq!(shrink("a\nb\nfail", |text| text.contains("fail")), "fail");
+    }
+
+    #[test]
+    fn removes_columns_of_grids() {
+        /* Two guards: every row and column without one goes, then the grid cannot lose a character */
+        let map = "....#.....\n.........#\n..........\n..#.......\n.......#..\n\
+                   ..........\n.#..^.....\n........#.\n#.........\n......^#..\n";
+        let two_guards = |text: &str| {
+            let width = text.lines().next().map(str::len);
+            text.matches('^').count() == 2 && text.lines().all(|l| Some(l.len()) == width)
+        };
+
+        assert_eq!(shrink(map, two_guards), "^.\n.^\n");
+        assert_eq!(shrink("..^..\n.....\n..^..\n", two_guards), "^\n^\n");
+    }
+
+    #[test]
+    fn removes_characters_of_lines() {
+        assert_eq!(shrink("xxaxxxbxx\n", |text| text.contains('a') && text.contains('b')), "ab\n");
+        assert_eq!(shrink("12 34 56\n7 8\n", |text| text.lines().count() == 2 && text.contains('5')), "5\n\n");
+    }
+
+    #[test]
+    fn errors_without_positions() {
+        let error = |line, column| format!("Invalid map: line {line}, column {column}: second guard '^', \
+                                             the first one is at line 4, column 2\n  |\n{line} | ..^\n  |   ^");
+
+        assert_eq!(error_kind(&error(7, 3)), "Invalid map: line _, column _: second guard '^', the first one is at line _, column _");
+        assert_eq!(error_kind(&error(7, 3)), error_kind(&error(12, 1)));
+        assert_ne!(error_kind("Invalid map: line 1, column 1: no guard"), error_kind("Invalid map: line 1, column 1: no start"));
+        assert_eq!(error_kind("timeline 3 of the pipeline"), "timeline _ of the pipeline");
+        assert_eq!(error_kind("line x, columns"), "line x, columns");
+    }
+}
@@ -0,0 +1,141 @@
+/* `aoc snapshot`: snapshot tests of the worlds rendered by the solvers.
+ *
+ * Each selected part is solved with its frames recorded, and its last frame, such as the patrol of
+ * day 6 or the compacted disk of day 9, is compared to its snapshot in `snapshots`. The input is
+ * generated by `aoc gen`, so that the snapshots can be committed, or is the example with `--test`.
+ * The parts which draw no frame are skipped.
+ *
+ * `cargo test` checks every snapshot of the folder against the input named by its file.
+ */
+use crate::cli;
+use crate::generate;
+use crate::registry::Solution;
+use aoc_common::animation::Frame;
+use aoc_common::args::Args;
+use aoc_common::runner::{self, Outcome, RunOptions};
+use aoc_common::snapshot::{self, Status};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "aoc snapshot [--day <N>] [--part <P>] [--year <Y>] [--tag <TAG>]... [--seed <S> | --test] [--update]";
+
+pub const SNAPSHOT_DIR: &str = "../snapshots";
+
+/// Snapshot of a part on the generated input of `seed`, or on the example when there is no seed.
+fn snapshot_file(solution: &Solution, seed: Option<u64>) -> PathBuf {
+    let source = seed.map(|s| format!("seed{s}")).unwrap_or("test".to_string());
+    format!("{SNAPSHOT_DIR}/{}_{}_{source}.{}", solution.input, solution.part, snapshot::EXTENSION).into()
+}
+
+/// Last frame drawn by a part on the generated input of `seed`, or on the example.
+fn last_frame(solution: &Solution, seed: Option<u64>, timeout: Option<Duration>) -> Result<Option<Frame>, String> {
+    let input = match seed {
+        Some(seed) => generate::generate(solution.year, solution.day, seed)
+                          .ok_or(format!("No input generator for year {}, day {}", solution.year, solution.day))?,
+        None => {
+            let path = cli::input_file(solution, true);
+            fs::read_to_string(&path).map_err(|e| format!("Could not read '{}': {e}", path.display()))?
+        },
+    };
+
+    let options = RunOptions { timeout, record_frames: true, ..Default::default() };
+    let report = runner::run_part(solution.solve, &input, &options);
+    if !matches!(report.outcome, Outcome::Solved(_)) {
+        return Err(report.outcome.to_string());
+    }
+
+    Ok(report.frames.into_iter().last())
+}
+
+/// Compares the last frame of a part with its snapshot, `None` when the part draws no frame.
+fn check(solution: &Solution, seed: Option<u64>, update: bool, timeout: Option<Duration>) -> Result<Option<Status>, String> {
+    last_frame(solution, seed, timeout)?
+        .map(|frame| snapshot::check(&snapshot_file(solution, seed), &snapshot::render_frame(&frame), update))
+        .transpose()
+}
+
+/// Checks the snapshots of the selected parts, returns whether they all match.
+pub fn command(args: &Args) -> Result<bool, String> {
+    let solutions = cli::selected_solutions(args, false)?;
+    let seed = match args.flag("--test") {
+        true => None,
+        false => Some(args.parsed_value("--seed")?.unwrap_or(0)),
+    };
+    let update = args.flag("--update");
+    let timeout = args.duration("--timeout")?;
+
+    let mut success = true;
+    for solution in solutions {
+        let label = cli::label(solution);
+        match check(solution, seed, update, timeout) {
+            Ok(None) => println!("{label}: no frame"),
+            Ok(Some(Status::Mismatched(diff))) => {
+                success = false;
+                println!("{label}: MISMATCH with '{}'\n{diff}", snapshot_file(solution, seed).display());
+            },
+            Ok(Some(status)) => println!("{label}: {status}"),
+            Err(e) => {
+                success = false;
+                println!("{label}: {e}");
+            },
+        }
+    }
+
+    if !success && !update {
+        println!("Review the differences, then run again with '--update' to accept them");
+    }
+    Ok(success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    /// Part and seed of a snapshot from its file name, the reverse of `snapshot_file`.
+    fn parse_snapshot_file(name: &str) -> Option<(&'static Solution, Option<u64>)> {
+        let mut fields = name.strip_suffix(&format!(".{}", snapshot::EXTENSION))?.rsplitn(3, '_');
+        let seed = match fields.next()? {
+            "test" => None,
+            source => Some(source.strip_prefix("seed")?.parse().ok()?),
+        };
+        let part: u8 = fields.next()?.parse().ok()?;
+        let input = fields.next()?;
+
+        let solution = registry::SOLUTIONS.iter().find(|s| s.input == input && s.part == part)?;
+        Some((solution, seed))
+    }
+
+    #[test]
+    fn snapshot_files() {
+        let parsed = |name| parse_snapshot_file(name).map(|(s, seed)| (s.year, s.day, s.part, seed));
+
+        assert_eq!(snapshot_file(registry::find(2024, 6, 1).unwrap(), Some(30)), PathBuf::from("../snapshots/aoc_06_1_seed30.snap"));
+        assert_eq!(snapshot_file(registry::find(2024, 10, 2).unwrap(), None), PathBuf::from("../snapshots/aoc_10_2_test.snap"));
+        assert_eq!(parsed("aoc_06_1_seed30.snap"), Some((2024, 6, 1, Some(30))));
+        assert_eq!(parsed("aoc_10_2_test.snap"), Some((2024, 10, 2, None)));
+
+        for name in ["aoc_06_1_seed30.txt", "aoc_06_3_seed0.snap", "aoc_06_1_seedx.snap", "aoc_99_1_test.snap", "x.snap"] {
+            assert!(parse_snapshot_file(name).is_none(), "{name}");
+        }
+    }
+
+    /// Every committed snapshot still matches the world drawn by its part.
+    #[test]
+    fn committed_snapshots() {
+        let mut names: Vec<String> = fs::read_dir(SNAPSHOT_DIR).unwrap()
+                                                              .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+                                                              .filter(|n| n.ends_with(snapshot::EXTENSION))
+                                                              .collect();
+        names.sort();
+        assert!(!names.is_empty());
+
+        for name in names {
+            let (solution, seed) = parse_snapshot_file(&name).unwrap_or_else(|| panic!("No part for the snapshot '{name}'"));
+            let frame = last_frame(solution, seed, None).unwrap_or_else(|e| panic!("{name}: {e}"))
+                                                        .unwrap_or_else(|| panic!("{name}: no frame"));
+            snapshot::assert_snapshot(format!("{SNAPSHOT_DIR}/{name}"), &snapshot::render_frame(&frame));
+        }
+    }
+}
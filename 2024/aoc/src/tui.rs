@@ -0,0 +1,415 @@
+/* `aoc tui`: dashboard of every part of the registry in the terminal.
+ *
+ * The list shows the status, the last answer and the run time of each part: at start, the answers
+ * of the answer cache and the medians of the last baseline of `aoc bench` on the personal input.
+ * The panel below it shows, for the selected part, the logs of its last run, the last frame of its
+ * grid, or its example and personal inputs.
+ *
+ * A part runs in a child `aoc run`, so that its logs are streamed to the panel and a panic does
+ * not break the screen. Its frames are written to a temporary file to show the grid. A single part
+ * runs at a time: running another one, or quitting, stops it.
+ *
+ * Keys: up/down select a part, enter runs it, t switches between the personal and example inputs,
+ * l/g/e/p show the logs, the grid, the example or the personal input, page up/down scroll the
+ * panel and q quits.
+ */
+use crate::bench;
+use crate::cache::{self, AnswerCache};
+use crate::cli;
+use crate::registry::{self, Solution};
+use aoc_common::input;
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::{Attribute, Print, SetAttribute};
+use crossterm::{cursor, execute, queue, terminal};
+use std::fs;
+use std::io::{self, BufRead, BufReader, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+pub const USAGE: &str = "aoc tui";
+
+/// Time between two refreshes while waiting for a key or a log line.
+const REFRESH: Duration = Duration::from_millis(100);
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum View {
+    Logs,
+    Grid,
+    Example,
+    Personal,
+}
+
+impl View {
+    fn title(&self) -> &str {
+        match self {
+            View::Logs => "Logs",
+            View::Grid => "Grid",
+            View::Example => "Example input",
+            View::Personal => "Personal input",
+        }
+    }
+}
+
+/// Line of the list, for a part.
+struct Row {
+    solution: &'static Solution,
+    status: String,
+    answer: String,
+    time: String,
+    logs: Vec<String>,
+    /// Last frame recorded by the solver, with its caption
+    grid: Vec<String>,
+}
+
+/// Message of the child running a part.
+enum RunEvent {
+    Log(String),
+    Done,
+}
+
+/// Child `aoc run` of a part, with its messages.
+struct Run {
+    /// Row of the part
+    row: usize,
+    child: Child,
+    receiver: Receiver<RunEvent>,
+}
+
+struct App {
+    rows: Vec<Row>,
+    selected: usize,
+    view: View,
+    scroll: usize,
+    test: bool,
+    running: Option<Run>,
+    frames_file: PathBuf,
+}
+
+impl App {
+    fn new() -> Result<Self, String> {
+        let cache = AnswerCache::load(Path::new(cache::CACHE_FILE))?;
+        let history = bench::load_history(Path::new(bench::HISTORY_FILE))?;
+
+        let rows = registry::SOLUTIONS.iter().map(|solution| {
+            let input = fs::read_to_string(cli::input_file(solution, false)).ok();
+            let (status, answer) = match &input {
+                None => ("no input", String::new()),
+                Some(input) => match cache.get(solution, input) {
+                    Some(answer) => ("cached", answer.to_string()),
+                    None => ("not run", String::new()),
+                },
+            };
+            let time = input.and_then(|i| bench::baseline(&history, solution.year, solution.day, solution.part, &input::hash(&i)))
+                              .map(|s| format!("{:.2?}", Duration::from_secs_f64(s.median)))
+                              .unwrap_or_default();
+
+            Row { solution, status: status.to_string(), answer, time, logs: Vec::new(), grid: Vec::new() }
+        }).collect();
+
+        let frames_file = std::env::temp_dir().join(format!("aoc_tui_frames_{}.txt", std::process::id()));
+        Ok(Self { rows, selected: 0, view: View::Logs, scroll: 0, test: false, running: None, frames_file })
+    }
+
+    /// Runs the selected part in a child `aoc run`, whose output is sent line by line. The part
+    /// running is stopped first, so that it does not write the frames of the new one.
+    fn run_selected(&mut self) -> Result<(), String> {
+        self.stop_run();
+        let _ = fs::remove_file(&self.frames_file);
+
+        let solution = self.rows[self.selected].solution;
+        let exe = std::env::current_exe().map_err(|e| format!("Could not find the aoc binary: {e}"))?;
+        let mut command = Command::new(exe);
+        command.args(["run", "--no-cache", "--year", &solution.year.to_string(), "--day", &solution.day.to_string(),
+                      "--part", &solution.part.to_string(), "--frames-out"])
+               .arg(&self.frames_file)
+               .stdin(Stdio::null())
+               .stdout(Stdio::piped())
+               .stderr(Stdio::piped());
+        if self.test {
+            command.arg("--test");
+        }
+        let mut child = command.spawn().map_err(|e| format!("Could not run the part: {e}"))?;
+
+        let (sender, receiver) = mpsc::channel();
+        let stdout = child.stdout.take().map(|out| forward_lines(out, sender.clone()));
+        let stderr = child.stderr.take().map(|err| forward_lines(err, sender.clone()));
+        thread::spawn(move || {
+            for reader in [stdout, stderr].into_iter().flatten() {
+                let _ = reader.join();
+            }
+            let _ = sender.send(RunEvent::Done);
+        });
+
+        let row = &mut self.rows[self.selected];
+        row.status = "running".to_string();
+        row.logs.clear();
+        self.running = Some(Run { row: self.selected, child, receiver });
+        self.view = View::Logs;
+        self.scroll = 0;
+        Ok(())
+    }
+
+    /// Kills the child of the running part, if any, and waits for it.
+    fn stop_run(&mut self) {
+        if let Some(mut run) = self.running.take() {
+            let _ = run.child.kill();
+            let _ = run.child.wait();
+            self.rows[run.row].status = "stopped".to_string();
+        }
+    }
+
+    /// Collects the output of the running part, and its result once it is done.
+    fn poll_run(&mut self) {
+        let Some(run) = &self.running else {
+            return;
+        };
+
+        let mut done = false;
+        while let Ok(event) = run.receiver.try_recv() {
+            match event {
+                RunEvent::Log(line) => self.rows[run.row].logs.push(line),
+                RunEvent::Done => done = true,
+            }
+        }
+        let Some(mut run) = self.running.take_if(|_| done) else {
+            return;
+        };
+
+        /* Its output is closed, the child is exiting */
+        let _ = run.child.wait();
+        let row = &mut self.rows[run.row];
+        let (status, answer, time) = parse_result(&cli::label(row.solution), &row.logs);
+        (row.status, row.answer, row.time) = (status, answer, time);
+        row.grid = fs::read_to_string(&self.frames_file).map(|text| last_frame(&text)).unwrap_or_default();
+        let _ = fs::remove_file(&self.frames_file);
+    }
+
+    /// Lines of the panel, for the selected part.
+    fn panel(&self) -> Vec<String> {
+        let row = &self.rows[self.selected];
+        let input = |test| match fs::read_to_string(cli::input_file(row.solution, test)) {
+            Ok(input) => input.lines().map(str::to_string).collect(),
+            Err(e) => vec![format!("Could not read '{}': {e}", cli::input_file(row.solution, test).display())],
+        };
+
+        match self.view {
+            View::Logs if row.logs.is_empty() => vec!["Not run yet, press enter to run the part".to_string()],
+            View::Logs => row.logs.clone(),
+            View::Grid if row.grid.is_empty() => vec!["No grid recorded, run a part which draws its grid".to_string()],
+            View::Grid => row.grid.clone(),
+            View::Example => input(true),
+            View::Personal => input(false),
+        }
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let (width, height) = terminal::size()?;
+        let (width, height) = (width as usize, height as usize);
+        let list_height = self.rows.len().min(height / 2);
+        let first = self.selected.saturating_sub(list_height.saturating_sub(1));
+
+        /* Lines of the screen, with whether they are highlighted */
+        let input = if self.test { "example" } else { "personal" };
+        let mut lines = vec![(format!("Advent of Code - {input} inputs - enter: run, t: inputs, l/g/e/p: panel, q: quit"), false)];
+        for (idx, row) in self.rows.iter().enumerate().skip(first).take(list_height) {
+            let solution = row.solution;
+            let title = solution.puzzle().map(|p| p.title).unwrap_or_default();
+            let answer = row.answer.lines().next().unwrap_or_default();
+            lines.push((format!("{} day {:2} part {}  {title:<24} {:<9} {answer:<20} {}", solution.year, solution.day,
+                                solution.part, row.status, row.time), idx == self.selected));
+        }
+        lines.push((format!("-- {}, {} --", self.view.title(), cli::label(self.rows[self.selected].solution)), false));
+        let panel_height = height.saturating_sub(lines.len());
+        lines.extend(self.panel().into_iter().skip(self.scroll).take(panel_height).map(|l| (l, false)));
+
+        for (y, (text, highlight)) in lines.iter().enumerate() {
+            let text: String = text.chars().take(width).collect();
+            queue!(out, cursor::MoveTo(0, y as u16))?;
+            match highlight {
+                true => queue!(out, SetAttribute(Attribute::Reverse), Print(&text), SetAttribute(Attribute::Reset))?,
+                false => queue!(out, Print(&text))?,
+            }
+            queue!(out, terminal::Clear(terminal::ClearType::UntilNewLine))?;
+        }
+        queue!(out, cursor::MoveToNextLine(1), terminal::Clear(terminal::ClearType::FromCursorDown))?;
+
+        out.flush()
+    }
+
+    fn scroll(&mut self, lines: isize) {
+        let max = self.panel().len().saturating_sub(1);
+        self.scroll = self.scroll.saturating_add_signed(lines).min(max);
+    }
+
+    /// Handles a key, returns whether to quit.
+    fn handle_key(&mut self, key: KeyCode) -> Result<bool, String> {
+        let show = |app: &mut Self, view| {
+            app.view = view;
+            app.scroll = 0;
+        };
+
+        match key {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+            KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down => self.selected = (self.selected + 1).min(self.rows.len() - 1),
+            KeyCode::Enter | KeyCode::Char('r') => self.run_selected()?,
+            KeyCode::Char('t') => self.test = !self.test,
+            KeyCode::Char('l') => show(self, View::Logs),
+            KeyCode::Char('g') => show(self, View::Grid),
+            KeyCode::Char('e') => show(self, View::Example),
+            KeyCode::Char('p') => show(self, View::Personal),
+            KeyCode::PageDown => self.scroll(10),
+            KeyCode::PageUp => self.scroll(-10),
+            _ => {},
+        }
+        if matches!(key, KeyCode::Up | KeyCode::Down) {
+            self.scroll = 0;
+        }
+
+        Ok(false)
+    }
+}
+
+fn forward_lines(output: impl Read + Send + 'static, sender: Sender<RunEvent>) -> thread::JoinHandle<()> {
+    thread::spawn(move || {
+        for line in BufReader::new(output).lines().map_while(Result::ok) {
+            let _ = sender.send(RunEvent::Log(line));
+        }
+    })
+}
+
+/// Status, answer and time of a part from the output of `aoc run`, where its result is written
+/// `<label>: <outcome> (<time>)`, or `<label> (<time>): ` followed by the lines of the outcome.
+fn parse_result(label: &str, logs: &[String]) -> (String, String, String) {
+    let Some(idx) = logs.iter().rposition(|l| l.starts_with(label)) else {
+        return ("no result".to_string(), String::new(), String::new());
+    };
+    let rest = &logs[idx][label.len()..];
+
+    let (outcome, time) = match rest.strip_prefix(": ") {
+        Some(rest) => match rest.rsplit_once(" (") {
+            Some((outcome, time)) => (outcome.to_string(), time.trim_end_matches(')').to_string()),
+            None => (rest.to_string(), String::new()),
+        },
+        None => {
+            /* The first line of an error is on the line of the label, a multi-line answer starts below */
+            let (time, first_line) = rest.trim_start_matches(" (").split_once("):").unwrap_or_default();
+            let lines: Vec<&str> = Some(first_line.trim_start()).filter(|l| !l.is_empty()).into_iter()
+                                                                .chain(logs[idx + 1..].iter().map(String::as_str))
+                                                                .collect();
+            (lines.join("\n"), time.to_string())
+        },
+    };
+
+    let status = match outcome.split_once(':').map(|(kind, _)| kind) {
+        Some("failed") => "failed",
+        Some("panicked") => "panicked",
+        Some("skipped") => "skipped",
+        _ if outcome.starts_with("timed out") => "timed out",
+        _ if outcome.starts_with("exceeded memory") => "no memory",
+        _ => "solved",
+    };
+    let answer = if status == "solved" { outcome } else { String::new() };
+    (status.to_string(), answer, time)
+}
+
+/// Caption and grid of the last frame of a file written by `--frames-out`.
+fn last_frame(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(start) = lines.iter().rposition(|l| l.starts_with("Frame ")) else {
+        return Vec::new();
+    };
+
+    lines[start..].iter().take_while(|l| !l.is_empty()).map(|l| l.to_string()).collect()
+}
+
+fn event_loop(app: &mut App) -> Result<(), String> {
+    let mut stdout = io::stdout();
+
+    loop {
+        app.poll_run();
+        app.draw(&mut stdout).map_err(|e| e.to_string())?;
+
+        if !event::poll(REFRESH).map_err(|e| e.to_string())? {
+            continue;
+        }
+        if let Event::Key(key) = event::read().map_err(|e| e.to_string())?
+            && key.kind == KeyEventKind::Press
+            && app.handle_key(key.code)?
+        {
+            return Ok(());
+        }
+    }
+}
+
+pub fn command() -> Result<bool, String> {
+    if !io::stdout().is_terminal() {
+        return Err("aoc tui needs a terminal".to_string());
+    }
+    let mut app = App::new()?;
+
+    terminal::enable_raw_mode().map_err(|e| e.to_string())?;
+    let mut stdout = io::stdout();
+    let _ = execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide, terminal::Clear(terminal::ClearType::All));
+
+    let result = event_loop(&mut app);
+    app.stop_run();
+
+    let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+    let _ = terminal::disable_raw_mode();
+    let _ = fs::remove_file(&app.frames_file);
+    result.map(|_| true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LABEL: &str = "2024 day 06 part 1";
+
+    fn result(logs: &[&str]) -> (String, String, String) {
+        let logs: Vec<String> = logs.iter().map(|l| l.to_string()).collect();
+        parse_result(LABEL, &logs)
+    }
+
+    fn strings(status: &str, answer: &str, time: &str) -> (String, String, String) {
+        (status.to_string(), answer.to_string(), time.to_string())
+    }
+
+    #[test]
+    fn results_of_the_runs() {
+        assert_eq!(result(&["progress", "2024 day 06 part 1: 41 (1.5ms)"]), strings("solved", "41", "1.5ms"));
+        assert_eq!(result(&["2024 day 06 part 1: abc (def) (2s)"]), strings("solved", "abc (def)", "2s"));
+        assert_eq!(result(&["2024 day 06 part 1: 41 (cached)"]), strings("solved", "41", "cached"));
+        assert_eq!(result(&["2024 day 06 part 1: panicked: index out of bounds (3ms)"]), strings("panicked", "", "3ms"));
+        assert_eq!(result(&["2024 day 06 part 1: timed out after 5s (5s)"]), strings("timed out", "", "5s"));
+        assert_eq!(result(&["2024 day 06 part 1: exceeded memory limit of 64 MiB (1s)"]), strings("no memory", "", "1s"));
+        assert_eq!(result(&["2024 day 06 part 1: skipped: Could not read 'x'"]), strings("skipped", "", ""));
+    }
+
+    #[test]
+    fn results_on_several_lines() {
+        /* The multi-line answers and the diagnostics follow the label and the time */
+        assert_eq!(result(&["2024 day 06 part 1 (4ms): ", "#..#", "####"]), strings("solved", "#..#\n####", "4ms"));
+        assert_eq!(result(&["2024 day 06 part 1 (4ms): failed: line 2, column 3: no guard", "  |", "2 | ..."]),
+                   strings("failed", "", "4ms"));
+
+        /* The last result of the part is the one of the run */
+        assert_eq!(result(&["2024 day 06 part 1: 1 (1ms)", "2024 day 06 part 1: 2 (2ms)"]), strings("solved", "2", "2ms"));
+        assert_eq!(result(&["2024 day 06 part 2: 6 (1ms)"]), strings("no result", "", ""));
+        assert_eq!(result(&[]), strings("no result", "", ""));
+    }
+
+    #[test]
+    fn last_frame_of_the_file() {
+        let text = "Frame 1/2: Start\n^.\n..\n\nFrame 2/2: Guard left after 1 steps\nX.\n..\n\n";
+
+        assert_eq!(last_frame(text), ["Frame 2/2: Guard left after 1 steps", "X.", ".."]);
+        assert_eq!(last_frame("Frame 1/1: \n#\n"), ["Frame 1/1: ", "#"]);
+        assert_eq!(last_frame(""), Vec::<String>::new());
+        assert_eq!(last_frame("no frame\n"), Vec::<String>::new());
+    }
+}
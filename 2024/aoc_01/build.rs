@@ -0,0 +1,26 @@
+/* ADVENT OF CODE
+ * Avec la feature `embed-input`, compile l'entrée du jour dans le binaire.
+ *
+ * L'entrée est le fichier de `AOC_INPUT` s'il est défini, sinon celui du dossier des entrées, nommé
+ * d'après le crate sans `_part2`, e.g. `../input_data/aoc_01.txt`. Ce script sert aussi au crate
+ * de la deuxième partie.
+ */
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=AOC_INPUT");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUT").is_none() {
+        return;
+    }
+
+    let name = env::var("CARGO_PKG_NAME").unwrap();
+    let input = env::var("AOC_INPUT").unwrap_or(format!("../input_data/{}.txt", name.trim_end_matches("_part2")));
+    println!("cargo:rerun-if-changed={input}");
+
+    let text = fs::read_to_string(&input)
+        .unwrap_or_else(|e| panic!("Impossible de lire l'entrée '{input}' à compiler dans le binaire: {e}"));
+    let output = PathBuf::from(env::var("OUT_DIR").unwrap()).join("input.txt");
+    fs::write(&output, text).unwrap_or_else(|e| panic!("Impossible d'écrire '{}': {e}", output.display()));
+}
@@ -0,0 +1,31 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/1
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+
+pub fn total_distance(list_1: &mut [i32], list_2: &mut [i32]) -> Answer {
+    /* Trier les deux listes dans l'ordre croissant */
+    list_1.sort();
+    list_2.sort();
+
+    /* Calculer la distance entre chaque élément des deux listes */
+    let mut list_distance: Vec<i64> = Vec::new();
+    let mut total_distance: i64 = 0;
+    for (idx, elt) in list_1.iter().enumerate() {
+        let distance = (*elt as i64 - list_2[idx] as i64).abs();
+        total_distance += distance;
+        list_distance.push(distance);
+    }
+
+    Answer::from(total_distance)
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    /* Chaque ligne contient un élément de chaque liste */
+    let lists: Vec<(i32, i32)> = parse::parse_lines(input, |c| Ok((c.integer()?, c.integer()?)))
+        .map_err(|e| format!("Liste invalide: {}", e.annotate(input)))?;
+    let (mut list_1, mut list_2): (Vec<i32>, Vec<i32>) = lists.into_iter().unzip();
+
+    Ok(total_distance(&mut list_1, &mut list_2))
+}
@@ -0,0 +1,59 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/1#part2
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use std::collections::HashMap;
+
+pub fn similarity(list_1: &mut [i32], list_2: &mut [i32]) -> Answer {
+    /* Trier les deux listes dans l'ordre croissant */
+    list_1.sort();
+    list_2.sort();
+
+    /* Calculer le nombre d'occurences de chaque élément list_1 dans list_2 */
+    let mut occurences: HashMap<i32, usize> = HashMap::new();
+    for e in list_1.iter() {
+        let count = list_2.iter().filter(|&n| n == e).count();
+        *occurences.entry(*e).or_default() += count;
+    }
+
+    /* Calculer la distance entre chaque élément des deux listes */
+    let mut similarity: i64 = 0;
+    for (number, count) in occurences {
+        similarity += number as i64 * count as i64;
+    }
+
+    Answer::from(similarity)
+}
+
+/* Variante en temps linéaire: les occurences de list_2 sont comptées une seule fois */
+pub fn similarity_counting(list_1: &[i32], list_2: &[i32]) -> Answer {
+    let mut occurences: HashMap<i32, i64> = HashMap::new();
+    for e in list_2 {
+        *occurences.entry(*e).or_default() += 1;
+    }
+
+    let similarity: i64 = list_1.iter().map(|e| *e as i64 * occurences.get(e).copied().unwrap_or_default()).sum();
+
+    Answer::from(similarity)
+}
+
+fn read_lists(input: &str) -> Result<(Vec<i32>, Vec<i32>), String> {
+    /* Chaque ligne contient un élément de chaque liste */
+    let lists: Vec<(i32, i32)> = parse::parse_lines(input, |c| Ok((c.integer()?, c.integer()?)))
+        .map_err(|e| format!("Liste invalide: {}", e.annotate(input)))?;
+
+    Ok(lists.into_iter().unzip())
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let (mut list_1, mut list_2) = read_lists(input)?;
+
+    Ok(similarity(&mut list_1, &mut list_2))
+}
+
+pub fn solve_counting(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let (list_1, list_2) = read_lists(input)?;
+
+    Ok(similarity_counting(&list_1, &list_2))
+}
@@ -0,0 +1,80 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/2
+ */
+
+use aoc_common::{Answer, Context};
+use aoc_common::parse::{self, Cursor};
+
+fn is_safe_report(report: &[i32]) -> bool {
+    let mut prev_report = 0;
+    let mut first_report = true;
+    let mut increasing = false;
+    let mut decreasing = false;
+
+    for &curr_report in report {
+        //println!("Curr report:{curr_report}, prev:{prev_report}");
+
+        if first_report {
+            first_report = false;
+            prev_report = curr_report;
+        }
+        else {
+            let variation = (prev_report - curr_report).abs();
+
+            if !(1..=3).contains(&variation) {
+                /* Reports are stationnary or varying too much => NOT SAFE */
+                return false;
+            }
+
+            if !increasing && !decreasing {
+                if prev_report < curr_report {
+                    increasing = true;
+                }
+                else if prev_report > curr_report {
+                    decreasing = true;
+                }
+                else {
+                    /* Impossible to reach as variation == 0 is tested earlier */
+                }
+            }
+            else {
+                if increasing && prev_report > curr_report {
+                    /* Decrease after increase => NOT SAFE */
+                    return false;
+                }
+                
+                if decreasing  && prev_report < curr_report {
+                    /* Increase after decrease => NOT SAFE */
+                    return false;
+                }
+            }
+
+            /* Store current report as prev report */
+            prev_report = curr_report;
+        }
+    }
+
+    /* If we arrive here, the report is SAFE */
+    true
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let mut safe_reports = 0;
+
+    /* Retrieve all the reports (one per line) of the input */
+    let reports: Vec<Vec<i32>> = parse::parse_lines(input, |c| c.many(Cursor::integer))
+        .map_err(|e| format!("Invalid report: {}", e.annotate(input)))?;
+
+    /* Iterate over reports */
+    for report in reports {
+        /* Verify report */
+        let report_result = is_safe_report(&report);
+
+        /* Update number of safe reports */
+        if report_result {
+            safe_reports += 1;
+        }
+    }
+
+    Ok(Answer::from(safe_reports))
+}
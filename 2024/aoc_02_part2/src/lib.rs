@@ -0,0 +1,105 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/2#part2
+ */
+
+use aoc_common::{Answer, Context};
+use aoc_common::parse::{self, Cursor};
+
+fn new_report_list_without_idx(reports: &[i32], idx: i32) -> Vec<i32> {
+    reports.iter().enumerate().filter(|(i, _)| *i as i32 != idx).map(|(_, report)| *report).collect()
+}
+ 
+fn is_safe_report(report: &Vec<i32>) -> bool {
+    let mut prev_report = 0;
+    let mut first_report = true;
+    let mut increasing = false;
+    let mut decreasing = false;
+
+    for state in report {
+        let curr_report = *state;
+
+        //println!("Curr report:{curr_report}, prev:{prev_report}");
+
+        if first_report {
+            first_report = false;
+            prev_report = curr_report;
+        }
+        else {
+            let variation = (prev_report - curr_report).abs();
+
+            if !(1..=3).contains(&variation) {
+                /* Reports are stationnary => NOT SAFE */
+                return false;
+            }
+
+            if !increasing && !decreasing {
+                if prev_report < curr_report {
+                    increasing = true;
+                }
+                else if prev_report > curr_report {
+                    decreasing = true;
+                }
+                else {
+                    /* Impossible to reach as variation == 0 is tested earlier */
+                }
+            }
+            else {
+                if increasing && prev_report > curr_report {
+                    /* Decrease after increase => NOT SAFE */
+                    return false;
+                }
+                
+                if decreasing  && prev_report < curr_report {
+                    /* Increase after decrease => NOT SAFE */
+                    return false;
+                }
+            }
+
+            /* Store current report as prev report */
+            prev_report = curr_report;
+        }
+    }
+
+    /* If we arrive here, the report is SAFE */
+    true
+}
+ 
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let mut safe_reports = 0;
+
+    /* Retrieve all the reports (one per line) of the input */
+    let reports: Vec<Vec<i32>> = parse::parse_lines(input, |c| c.many(Cursor::integer))
+        .map_err(|e| format!("Could not create report list: {}", e.annotate(input)))?;
+
+    /* Iterate over reports */
+    for report in reports {
+        let report_count = report.len();
+        let mut report_is_safe = false;
+
+        /* Verify the full report list */
+        if is_safe_report(&report) {
+            /* Indicate that the report is safe */
+            report_is_safe = true;
+        }
+        else { /* Need to try other report list with one report removed */
+            for rep_idx in 0..report_count {
+                let new_report = new_report_list_without_idx(&report, rep_idx as i32);
+
+                /* Verify the report list */
+                if is_safe_report(&new_report) {
+                    /* Indicate that the report is safe */
+                    report_is_safe = true;
+                    /* Stop the for loop */
+                    break;
+                }
+            }
+        } 
+
+        if report_is_safe {
+            /* Update number of safe reports */
+            safe_reports += 1;
+        }
+    }
+
+    Ok(Answer::from(safe_reports))
+}
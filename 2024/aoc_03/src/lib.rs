@@ -0,0 +1,25 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/3
+ */
+use aoc_common::{Answer, Context};
+use regex::Regex;
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    /* Iterate over the commands of each line of the input */
+    let mut total: i64 = 0;
+    let re = Regex::new(r#"mul\((\d+),(\d+)\)"#).unwrap();
+    for line in input.lines() {
+        for command in re.captures_iter(line) {
+            // println!("command: {:?}", command);
+
+            if command[0].contains("mul(") {
+                let val_a: i64 = command[1].parse().map_err(|e| format!("Invalid operand '{}': {e}", &command[1]))?;
+                let val_b: i64 = command[2].parse().map_err(|e| format!("Invalid operand '{}': {e}", &command[2]))?;
+
+                total += val_a * val_b;
+            }
+        }
+    }
+
+    Ok(Answer::from(total))
+}
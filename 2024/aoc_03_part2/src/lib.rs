@@ -0,0 +1,32 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/3#part2
+ */
+use aoc_common::{Answer, Context};
+use regex::Regex;
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    /* Iterate over the commands of each line of the input */
+    let mut mult_enabled = true;
+    let mut total: i64 = 0;
+    let re = Regex::new(r#"do\(\)|mul\((\d+),(\d+)\)|don't\(\)"#).unwrap();
+    for line in input.lines() {
+        for command in re.captures_iter(line) {
+            // println!("command: {:?}", command);
+
+            if command[0].contains("don't()") {
+                mult_enabled = false;
+            }
+            else if command[0].contains("do()") {
+                mult_enabled = true;
+            }
+            else if command[0].contains("mul(") && mult_enabled {
+                let val_a: i64 = command[1].parse().map_err(|e| format!("Invalid operand '{}': {e}", &command[1]))?;
+                let val_b: i64 = command[2].parse().map_err(|e| format!("Invalid operand '{}': {e}", &command[2]))?;
+
+                total += val_a * val_b;
+            }
+        }
+    }
+
+    Ok(Answer::from(total))
+}
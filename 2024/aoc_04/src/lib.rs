@@ -0,0 +1,127 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/4
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use regex::Regex;
+
+fn create_columns(list: &[String]) -> Vec<String> {
+    let mut columns = Vec::new();
+    let items_count = list[0].len();
+
+    for idx_c in 0..items_count {
+        let mut row = String::with_capacity(items_count);
+        for line in list.iter().take(items_count) {
+            if let Some(c) = line.chars().nth(idx_c) {
+                row.push(c);
+            }
+        }
+        columns.push(row);
+    }
+
+    columns
+}
+
+/* The lines and the characters of a diagonal are both indexed from its start */
+#[allow(clippy::needless_range_loop)]
+fn create_diagonals(list: &[String], left_right: bool) -> Vec<String> {
+    let mut result = Vec::new();
+    let char_count = list[0].len();
+
+    if left_right {
+        /* Create diagonals left to right from each char of first line */
+        for char_idx in 0..char_count {
+            let mut diag = String::with_capacity(char_count);
+            for i in 0..(char_count-char_idx) {
+                if let Some(c) = list[i].chars().nth(char_idx + i) {
+                    diag.push(c);
+                }
+            }
+            result.push(diag);
+        }
+
+        // /* Create diagonals left to right from each first char of each line */
+        for row_idx in 1..char_count {
+            let mut diag = String::with_capacity(char_count);
+            for i in 0..(char_count - row_idx) {
+                if let Some(c) = list[row_idx + i].chars().nth(i) {
+                    diag.push(c);
+                }
+            }
+            result.push(diag);
+        }
+    }
+    else {
+        /* Create diagonals right to left from each char of first line */
+        for char_idx in 0..char_count {
+            let mut diag = String::with_capacity(char_count);
+            for i in 0..(char_count - char_idx) {
+                if let Some(c) = list[i].chars().nth((char_count-char_idx-1) - i) {
+                    diag.push(c);
+                }
+            }
+            result.push(diag);
+        }
+
+        /* Create diagonals right to left from each first char of each line */
+        for char_idx in 1..char_count {
+            let mut diag = String::with_capacity(char_count);
+            for i in 0..(char_count-char_idx) {
+                if let Some(c) = list[char_idx + i].chars().nth((char_count-1) - i) {
+                    diag.push(c);
+                }
+            }
+            result.push(diag);
+        }
+    }
+
+    result
+}
+
+fn count_occurences(list: &Vec<String>, pattern: &str) -> usize {
+    let mut occurences = 0;
+    if let Ok(result) = Regex::new(pattern) {
+        for line in list {
+            let count = result.captures_iter(line.as_str()).count();
+            occurences += count;
+        }
+    }
+    
+    occurences
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let mut total_occurences = 0;
+
+    /*****************************************************
+     * COUNT THE OCCURENCES IN THE LINES OF THE MATRIX
+     */
+    let puzzle = parse::grid(input, "'X', 'M', 'A' or 'S'", |c| "XMAS".contains(c))
+        .map_err(|e| format!("Invalid puzzle: {}", e.annotate(input)))?;
+    let row_list: Vec<String> = puzzle.lines.iter().map(|l| l.to_string()).collect();
+    total_occurences += count_occurences(&row_list, "XMAS");
+    total_occurences += count_occurences(&row_list, "SAMX");
+
+    /*****************************************************
+     * COUNT THE OCCURENCES IN THE COLUMNS OF THE MATRIX
+     */
+    let list = create_columns(&row_list);
+    total_occurences += count_occurences(&list, "XMAS");
+    total_occurences += count_occurences(&list, "SAMX");
+
+    /*****************************************************
+     * COUNT THE OCCURENCES IN THE DIAGONALS (BL to UR and reverse) OF THE MATRIX
+     */
+    let list = create_diagonals(&row_list, true);
+    total_occurences += count_occurences(&list, "XMAS");
+    total_occurences += count_occurences(&list, "SAMX");
+
+    /*****************************************************
+     * COUNT THE OCCURENCES IN THE DIAGONALS (TL to BR and reverse) OF THE MATRIX
+     */
+    let list = create_diagonals(&row_list, false);
+    total_occurences += count_occurences(&list, "XMAS");
+    total_occurences += count_occurences(&list, "SAMX");
+
+    Ok(Answer::from(total_occurences))
+}
@@ -0,0 +1,101 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/4#part2
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use std::option::Option;
+
+fn read_as_array(input: &str) -> Option<Vec<Vec<char>>> {
+    let mut result: Vec<Vec<char>> = Vec::new();
+
+    for line in input.lines() {
+        let mut row = Vec::new();
+
+        for c in line.chars() {
+            row.push(c);
+        }
+        result.push(row);
+    }
+
+    Some(result)
+}
+
+fn get_char_from_table(table: &[Vec<char>], row: usize, col: usize) -> char {
+    if let Some(r) = table.get(row) {
+        if let Some(&character) = r.get(col) {
+            return character;
+        };
+    }
+    0 as char
+}
+
+fn search_patterns(table: &[Vec<char>], start_row: usize, start_col: usize) -> bool {
+    let first_char = get_char_from_table(table, start_row, start_col);
+
+    /* Verify that first character is correct and that we won't be out of bounds during search 
+     * Note: table is a square table, so we simplify the verification as we know that each line
+     *       will be the size of the number of lines.
+     */
+    if (first_char != 'M' && first_char != 'S') ||
+       (start_row + 2) >= table.len() ||
+       (start_col + 2) >= table.len() {
+        return false;
+    }
+
+    /* If first char is M, search for the following patterns:
+     *   M.S      M.M
+     *   .A.      .A.
+     *   M.S      S.S
+     *
+     */
+     if (get_char_from_table(table, start_row    , start_col    ) == 'M' && get_char_from_table(table, start_row    , start_col + 2) == 'S' &&
+         get_char_from_table(table, start_row + 1, start_col + 1) == 'A' &&
+         get_char_from_table(table, start_row + 2, start_col    ) == 'M' && get_char_from_table(table, start_row + 2, start_col + 2) == 'S') ||
+        (get_char_from_table(table, start_row    , start_col    ) == 'M' && get_char_from_table(table, start_row    , start_col + 2) == 'M' &&
+         get_char_from_table(table, start_row + 1, start_col + 1) == 'A' &&
+         get_char_from_table(table, start_row + 2, start_col    ) == 'S' && get_char_from_table(table, start_row + 2, start_col + 2) == 'S') {
+            return true;
+     }
+
+    /* If first char is S, search for the following patterns:
+     *   S.M      S.S
+     *   .A.      .A.
+     *   S.M      M.M
+     *
+     */
+     if (get_char_from_table(table, start_row    , start_col    ) == 'S' && get_char_from_table(table, start_row    , start_col + 2) == 'M' &&
+         get_char_from_table(table, start_row + 1, start_col + 1) == 'A' &&
+         get_char_from_table(table, start_row + 2, start_col    ) == 'S' && get_char_from_table(table, start_row + 2, start_col + 2) == 'M') ||
+        (get_char_from_table(table, start_row    , start_col    ) == 'S' && get_char_from_table(table, start_row    , start_col + 2) == 'S' &&
+         get_char_from_table(table, start_row + 1, start_col + 1) == 'A' &&
+         get_char_from_table(table, start_row + 2, start_col    ) == 'M' && get_char_from_table(table, start_row + 2, start_col + 2) == 'M') {
+            return true;
+     }
+
+    false
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    let mut total_occurences = 0;
+
+    /* Read the input, a rectangular grid of letters, and organize data as Vec<Vec<char>> */
+    parse::grid(input, "'X', 'M', 'A' or 'S'", |c| "XMAS".contains(c))
+        .map_err(|e| format!("Invalid puzzle: {}", e.annotate(input)))?;
+    let row_list = match read_as_array(input) {
+        Some(a) => a,
+        None => return Err("Could not read the input as an array".to_string()),
+    };
+
+    /* Search for M or S character which can be the start of the X-MAS pattern */
+    for (idx_r, r) in row_list.iter().enumerate() {
+        for (idx_c, c) in r.iter().enumerate() {
+            /* If character found, search for the X-MAS pattern */
+            if (*c == 'M' || *c == 'S')
+                && search_patterns(&row_list, idx_r, idx_c) {
+                    total_occurences += 1;
+                }
+        }
+    }
+
+    Ok(Answer::from(total_occurences))
+}
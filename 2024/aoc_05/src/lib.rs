@@ -0,0 +1,59 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/5
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse::{self, Cursor, ParseError, Section};
+
+fn create_rules(section: &Section) -> Result<Vec<(i32, i32)>, ParseError> {
+    /* Each rule is written as "X|Y" */
+    section.parse_lines(|c| {
+        let val_1 = c.integer()?;
+        c.literal("|")?;
+        let val_2 = c.integer()?;
+
+        Ok((val_1, val_2))
+    })
+}
+
+fn create_updates(section: &Section) -> Result<Vec<Vec<i32>>, ParseError> {
+    /* Each update is a list of pages separated by commas */
+    section.parse_lines(|c| c.separated(",", Cursor::integer))
+}
+
+pub fn solve(input: &str, _ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * CREATE LIST OF RULES AND UPDATES
+     */
+    let sections = parse::sections_exact(input, &["rules", "updates"]).map_err(|e| e.annotate(input))?;
+
+    let rules = create_rules(&sections[0]).map_err(|e| format!("Invalid rule: {}", e.annotate(input)))?;
+    let updates = create_updates(&sections[1]).map_err(|e| format!("Invalid update: {}", e.annotate(input)))?;
+
+    /*****************************************************
+     * VERIFY EACH UPDATE WITH THE SET OF RULES
+     */
+     let mut invalid_rules;
+     let mut total = 0;
+
+    for update in updates {
+        invalid_rules = 0;
+        for (first_page, other_page) in &rules {
+            let p1_idx = update.iter().position(|n| n == first_page).unwrap_or(usize::MAX);
+            let p2_idx = update.iter().position(|n| n == other_page).unwrap_or(usize::MAX);
+
+            if p1_idx != usize::MAX && p2_idx != usize::MAX && p1_idx > p2_idx {
+                invalid_rules += 1;
+            }
+        }
+
+        if invalid_rules == 0 {
+            /* Find the middle page number */
+            let middle = update.len() / 2;
+
+            /* Add it to total */
+            total += update[middle];
+        }
+    }
+
+    Ok(Answer::from(total))
+}
@@ -0,0 +1,134 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/6
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use aoc_common::animation::{Colour, Frame, Palette};
+use aoc_common::grid::Grid;
+
+const PALETTE: Palette = &[
+    ('.', Colour::rgb(40, 40, 40)),
+    ('#', Colour::rgb(128, 128, 128)),
+    ('X', Colour::rgb(80, 160, 255)),
+    ('^', Colour::rgb(255, 200, 0)),
+    ('v', Colour::rgb(255, 200, 0)),
+    ('<', Colour::rgb(255, 200, 0)),
+    ('>', Colour::rgb(255, 200, 0)),
+];
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug)]
+enum Direction {
+    UP,
+    RIGHT,
+    DOWN,
+    LEFT
+}
+
+fn turn_right(direction: &mut Direction) {
+    *direction = match *direction {
+        Direction::UP => Direction::RIGHT,
+        Direction::DOWN => Direction::LEFT,
+        Direction::LEFT => Direction::UP,
+        Direction::RIGHT => Direction::DOWN,
+    };
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    let mut guard;
+
+    /*****************************************************
+     * CREATE WORLD
+     */
+    /* The map must be rectangular, with a single guard facing up */
+    let invalid_map = |e: parse::ParseError| format!("Invalid map: {}", e.annotate(input));
+    let map = parse::grid(input, "'.', '#' or '^'", |c| ".#^".contains(c)).map_err(invalid_map)?;
+    let (guard_x, guard_y) = map.find_unique('^', "guard").map_err(invalid_map)?;
+    let mut world: Vec<String> = map.lines.iter().map(|l| l.to_string()).collect();
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+
+    /*****************************************************
+     * SEARCH THE GUARD IN THE WORLD
+     */
+    let mut x;
+    let mut y;
+    let mut new_x;
+    let mut new_y;
+    let mut direction;
+
+    x = guard_x as i32;
+    y = guard_y as i32;
+    direction = Direction::UP;
+
+    /*****************************************************
+     * MOVE THE GUARD UNTIL IT LEAVES THE WORLD
+     */
+    let mut guard_disappeared = false;
+    let mut steps = 0;
+    while !guard_disappeared {
+        /* Compute new position */
+        match direction {
+            Direction::UP => {
+                guard = "^";
+                new_x = x;
+                new_y = y - 1;
+            },
+            Direction::DOWN => {
+                guard = "v";
+                new_x = x;
+                new_y = y + 1;
+            },
+            Direction::LEFT => {
+                guard = "<";
+                new_x = x - 1;
+                new_y = y;
+            },
+            Direction::RIGHT => {
+                guard = ">";
+                new_x = x + 1;
+                new_y = y;
+            },
+        }
+
+        /* Is guard outside the world ? */
+        if new_x < 0 || new_y < 0 || new_x >= world_x_len || new_y >= world_y_len {
+            /* Paint where the guard was before moving */
+            world[y as usize].replace_range(x as usize..x as usize +1, "X");
+            /* Indicates that the guard disappeared */
+            guard_disappeared = true;
+        }
+        else {
+            /* Is there an obstacle ? */
+            if world[new_y as usize].chars().nth(new_x as usize).unwrap() == '#' {
+                /* Change direction by turning right */
+                turn_right(&mut direction);
+                /* Do not change position of the guard */
+            }
+            else {
+                /* Paint the area where the guard is */
+                world[y as usize].replace_range(x as usize..x as usize+1, "X");
+                /* Move the guard */
+                x = new_x;
+                y = new_y;
+                world[y as usize].replace_range(x as usize..x as usize+1, guard);
+                steps += 1;
+
+                /* Record the move for the animation */
+                ctx.frame(|| Frame::new(Grid::from_lines(&world), PALETTE).with_caption(format!("Step {steps}")));
+            }
+        }
+    }
+
+    ctx.last_frame(|| Frame::new(Grid::from_lines(&world), PALETTE).with_caption(format!("Guard left after {steps} steps")));
+
+    /*****************************************************
+     * COMPUTE AREA VIEWED BY THE GUARD
+     */
+    let mut area = 0;
+    for line in world {
+        area += line.chars().filter(|c| *c == 'X').count();
+    }
+
+    Ok(Answer::from(area))
+}
@@ -0,0 +1,304 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/6#part2
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::checkpoint::Checkpoint;
+use aoc_common::parse;
+use std::collections::HashMap;
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Direction {
+    UP,
+    RIGHT,
+    DOWN,
+    LEFT
+}
+
+fn turn_right(direction: &mut Direction) {
+    *direction = match *direction {
+        Direction::UP => Direction::RIGHT,
+        Direction::DOWN => Direction::LEFT,
+        Direction::LEFT => Direction::UP,
+        Direction::RIGHT => Direction::DOWN,
+    };
+}
+
+fn get_unique_cell_id(world: &[String], x: &i32, y: &i32) -> i32 {
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+
+    if *x < world_x_len && *y < world_y_len {
+        *y * world_x_len + *x
+    }
+    else {
+        -1_i32
+    }
+}
+
+fn change_world_cell_value(world: &mut [String], x: &i32, y: &i32, value: &str) {
+        world[*y as usize].replace_range(*x as usize..*x as usize+1, value);
+}
+
+fn put_obstruction_in_world(world: &mut [String], obstruction_x: &mut i32, obstruction_y: &mut i32) -> bool {
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+    let mut obstruction_placed = false;
+
+    while !obstruction_placed {
+        if *obstruction_x == world_x_len {
+            *obstruction_x = 0;
+            *obstruction_y += 1;
+        }
+        if *obstruction_y >= world_y_len {
+            *obstruction_x = world_x_len;
+            *obstruction_y = world_y_len;
+            return false;
+        }
+
+        let current_cell = world[*obstruction_y as usize].chars().nth(*obstruction_x as usize).unwrap();
+        if current_cell == '.' {
+            //println!("Obstruction placed at {:?},{:?}", obstruction_x, obstruction_y);
+            obstruction_placed = true;
+            change_world_cell_value(world, obstruction_x, obstruction_y, "O");
+        }
+        *obstruction_x += 1;
+
+
+    }    
+    obstruction_placed
+}
+
+fn reset_world<'a>(world: &mut [String], guard: &mut &'a str, direction: &mut Direction, guard_position: &(i32, i32, Direction, &'a str)) {
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+
+    /* Reset cells except obstacles */
+    for x in 0..world_x_len {
+        for y in 0..world_y_len {
+            let current_cell = world[y as usize].chars().nth(x as usize).unwrap();
+            if current_cell != '#' {
+                change_world_cell_value(world, &x, &y, ".");
+            }
+        }
+    }
+
+    /* Put the gard at it original position */
+    change_world_cell_value(world, &guard_position.0, &guard_position.1, guard_position.3);
+    *guard = guard_position.3;
+    *direction = guard_position.2;
+}
+
+/* The obstruction placed last is the one before the current position */
+fn save_progress(obstruction_x: i32, obstruction_y: i32, loop_count: i32) -> Checkpoint {
+    Checkpoint::new().with("obstruction_x", obstruction_x - 1)
+                     .with("obstruction_y", obstruction_y)
+                     .with("loop_count", loop_count)
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    let mut guard = "^";
+
+    /*****************************************************
+     * CREATE WORLD
+     */
+    /* The map must be rectangular, with a single guard facing up */
+    let parse_span = ctx.span("parse world");
+    let invalid_map = |e: parse::ParseError| format!("Invalid map: {}", e.annotate(input));
+    let map = parse::grid(input, "'.', '#' or '^'", |c| ".#^".contains(c)).map_err(invalid_map)?;
+    let (guard_x, guard_y) = map.find_unique('^', "guard").map_err(invalid_map)?;
+    let mut world: Vec<String> = map.lines.iter().map(|l| l.to_string()).collect();
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+    drop(parse_span);
+
+    /*****************************************************
+     * SEARCH THE GUARD IN THE WORLD
+     */
+    let mut x;
+    let mut y;
+    let mut new_x;
+    let mut new_y;
+    let mut direction;
+    let mut prev_direction;
+    let mut obstruction_x = 0;
+    let mut obstruction_y = 0;
+
+    x = guard_x as i32;
+    y = guard_y as i32;
+    direction = Direction::UP;
+    prev_direction = direction;
+    let guard_position = (x, y, direction, guard);
+
+    /*****************************************************
+     * MOVE THE GUARD UNTIL IT LEAVES THE WORLD
+     */
+    let mut loop_count = 0;
+    let mut all_mapped_tested = false;
+        let mut obstacles_hit: HashMap<i32, Direction> = HashMap::new();
+
+    /* Resume the sweep from the obstruction being tested when the run was interrupted */
+    if let Some(checkpoint) = ctx.resumed_checkpoint() {
+        obstruction_x = checkpoint.require("obstruction_x")?;
+        obstruction_y = checkpoint.require("obstruction_y")?;
+        loop_count = checkpoint.require("loop_count")?;
+    }
+
+    /* Put the first obstruction on the world */
+    if !put_obstruction_in_world(&mut world, &mut obstruction_x, &mut obstruction_y) {
+        return Err("Could not put any obstruction in the world !".to_string());
+    }
+
+    let _simulation_span = ctx.span("simulation");
+
+    while !all_mapped_tested {
+        /* Compute new position */
+        match direction {
+            Direction::UP => {
+                guard = "^";
+                new_x = x;
+                new_y = y - 1;
+            },
+            Direction::DOWN => {
+                guard = "v";
+                new_x = x;
+                new_y = y + 1;
+            },
+            Direction::LEFT => {
+                guard = "<";
+                new_x = x - 1;
+                new_y = y;
+            },
+            Direction::RIGHT => {
+                guard = ">";
+                new_x = x + 1;
+                new_y = y;
+            },
+        }
+
+        /* Is guard outside the world ? */
+        if new_x < 0 || new_y < 0 || new_x >= world_x_len || new_y >= world_y_len {
+            /* Reset obstacle list */
+            obstacles_hit.clear();
+
+            /* Reset the guard's position */
+            x = guard_position.0;
+            y = guard_position.1;
+            let reset_span = ctx.span("reset world");
+            reset_world(&mut world, &mut guard, &mut direction, &guard_position);
+            drop(reset_span);
+
+            /* Set the new obstruction */
+            if !put_obstruction_in_world(&mut world, &mut obstruction_x, &mut obstruction_y) {
+                if obstruction_x == world_x_len && obstruction_y == world_y_len {
+                    all_mapped_tested = true;
+                }
+                else {
+                    return Err("Unknown error !".to_string());
+                }
+            }
+
+            /* Report the progress of the sweep and stop if asked to */
+            ctx.progress((obstruction_y * world_x_len + obstruction_x) as u64, (world_x_len * world_y_len) as u64);
+            if !all_mapped_tested {
+                ctx.checkpoint(|| save_progress(obstruction_x, obstruction_y, loop_count));
+            }
+            ctx.check_cancelled()?;
+        }
+        else {
+            /* Is there an obstacle ? */
+            let current_cell = world[new_y as usize].chars().nth(new_x as usize).unwrap();
+            if current_cell == '#' || current_cell == 'O' {
+                let cell_id = get_unique_cell_id(&world, &new_x, &new_y);
+                /* If the obstacle is "hit" from the same direction twice, then you're in a loop */
+                if obstacles_hit.contains_key(&cell_id) && *obstacles_hit.get(&cell_id).unwrap() == direction {
+                    /* Increment the number of loops */
+                    loop_count += 1;
+
+                    /* Reset obstacle list */
+                    obstacles_hit.clear();
+
+                    /* Reset the guard's position */
+                    x = guard_position.0;
+                    y = guard_position.1;
+                    let reset_span = ctx.span("reset world");
+                    reset_world(&mut world, &mut guard, &mut direction, &guard_position);
+                    drop(reset_span);
+
+                    /* Set the new obstruction */
+                    if !put_obstruction_in_world(&mut world, &mut obstruction_x, &mut obstruction_y) {
+                        if obstruction_x == world_x_len && obstruction_y == world_y_len {
+                            all_mapped_tested = true;
+                        }
+                        else {
+                            return Err("Unknown error !".to_string());
+                        }
+                    }
+
+                    /* Report the progress of the sweep and stop if asked to */
+                    ctx.progress((obstruction_y * world_x_len + obstruction_x) as u64, (world_x_len * world_y_len) as u64);
+                    if !all_mapped_tested {
+                        ctx.checkpoint(|| save_progress(obstruction_x, obstruction_y, loop_count));
+                    }
+                    ctx.check_cancelled()?;
+                }
+                /* Else */
+                else {
+                    /* Store the current cell position and the direction */
+                    obstacles_hit.insert(cell_id,direction);
+
+                    /* Change direction by turning right */
+                    turn_right(&mut direction);
+                    /* Do not change position of the guard */
+                }
+            }
+            else {
+                /* Paint the area where the guard is */
+                match direction {
+                    Direction::UP => {
+                        if prev_direction == direction {
+                            change_world_cell_value(&mut world, &x, &y, "|");
+                        }
+                        else {
+                            change_world_cell_value(&mut world, &x, &y, "+");
+                        }
+                    },
+                    Direction::DOWN => {
+                        if prev_direction == direction {
+                            change_world_cell_value(&mut world, &x, &y, "|");
+                        }
+                        else {
+                            change_world_cell_value(&mut world, &x, &y, "+");
+                        }
+                    },
+                    Direction::LEFT => {
+                        if prev_direction == direction {
+                            change_world_cell_value(&mut world, &x, &y, "-");
+                        }
+                        else {
+                            change_world_cell_value(&mut world, &x, &y, "+");
+                        }
+                    },
+                    Direction::RIGHT => {
+                        if prev_direction == direction {
+                            change_world_cell_value(&mut world, &x, &y, "-");
+                        }
+                        else {
+                            change_world_cell_value(&mut world, &x, &y, "+");
+                        }
+                    },
+                }
+                
+                /* Move the guard */
+                x = new_x;
+                y = new_y;
+                change_world_cell_value(&mut world, &x, &y, guard);
+
+                /* Store current direction */
+                prev_direction = direction;
+            }
+        }
+    }
+
+    Ok(Answer::from(loop_count))
+}
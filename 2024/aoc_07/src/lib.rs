@@ -0,0 +1,88 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/7
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::inspect::{self, Stat};
+use aoc_common::parse;
+
+fn equation_is_valid(operands: &mut [i64], expected_result: i64) -> bool {
+    /* Note: For N operands we need N-1 operators, so there are 2^(N-1) possibilities to test.
+     *       We decide to store the number of possibilities in a u32 integer and we decide that
+     *       for each possibility value, a 0b value means addition and 1b means multiplication.
+     *       Each bit of the possibility value represents the position of each operator in the
+     *       equation.
+     */
+     let mut equation_is_valid = false;
+     let mut possibility: u32 = 0;
+     let max_possibilities = match operands.len() {
+                                0 => 0,
+                                _ => 2u32.pow(operands.len() as u32 - 1),
+                            };
+
+     while !equation_is_valid && possibility < max_possibilities {
+        let mut result = operands[0];
+        for (idx, operand) in operands[1..operands.len()].iter().enumerate() {
+            if possibility & (1 << idx) > 0 {
+                result *= operand;
+            }
+            else {
+                result += operand;
+            }
+        }
+        if result == expected_result {
+            equation_is_valid = true;
+        }
+
+        possibility += 1;
+     }
+
+     equation_is_valid
+}
+
+/* Each equation is written as "result: operand operand ...", with non-negative numbers */
+fn read_equations(input: &str) -> Result<Vec<(i64, Vec<i64>)>, String> {
+    parse::parse_lines(input, |c| {
+        let result = c.integer_in(0..)?;
+        c.literal_exact(":")?;
+        let operands = c.many(|c| c.integer_in(0..))?;
+
+        Ok((result, operands))
+    }).map_err(|e| format!("Invalid equation: {}", e.annotate(input)))
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * CREATE LIST OF EQUATIONS
+     */
+    let equations = ctx.measure("parse", || read_equations(input))?;
+
+    /*****************************************************
+     * VERIFY EACH EQUATION
+     */
+     let mut calibration = 0;
+
+    for (result, mut operands) in equations {
+        /* Test all possible combination of operators for the equation */
+        if equation_is_valid(&mut operands, result) {
+            calibration += result;
+        }
+    }
+
+    Ok(Answer::from(calibration))
+}
+
+/* Statistics shown by `aoc inspect`: the operators to try grow exponentially with the operands */
+pub fn inspect(input: &str) -> Result<Vec<Stat>, String> {
+    let equations = read_equations(input)?;
+    let combinations = |operators: f64| -> f64 {
+        equations.iter().map(|(_, operands)| operators.powi(operands.len() as i32 - 1)).sum()
+    };
+
+    Ok(vec![
+        Stat::new("Equations", equations.len()),
+        Stat::new("Operands by equation", inspect::distribution(equations.iter().map(|(_, operands)| operands.len()))),
+        Stat::new("Largest result", equations.iter().map(|(result, _)| *result).max().unwrap_or_default()),
+        Stat::new("Combinations of 2 operators", combinations(2.0)),
+        Stat::new("Combinations of 3 operators", combinations(3.0)),
+    ])
+}
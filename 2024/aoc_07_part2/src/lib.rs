@@ -0,0 +1,126 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/7#part2
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::checkpoint::Checkpoint;
+use aoc_common::parse;
+
+fn equation_is_valid(operands: &mut [i64], expected_result: i64) -> bool {
+    /* Note: For N operands we need N-1 operators, so there are X^(N-1) possibilities to test,
+     *       WHERE X is the number of possible operators, here 3: +, *, || to concatenate 2 operands.
+     *       We decide to store the number of possibilities in a Vec<u8> integer and we decide that
+     *       for each possibility value, a 0 value means addition and 1 means multiplication and 2
+     *       means concatenation.
+     *       Each bit of the possibility value represents the position of each operator in the
+     *       equation.
+     */
+     let mut equation_is_valid = false;
+     let mut possibility: Vec<u8> = vec![0; operands.len()];
+     let mut possibility_count = 0;
+     let max_possibilities = match operands.len() {
+                                0 => 0,
+                                _ => 3u32.pow(operands.len() as u32 - 1),
+                            };
+
+     while !equation_is_valid && possibility_count < max_possibilities {
+        /* Create the equation as a string vector */
+        let mut equation: Vec<String> = Vec::new();
+        equation.push(operands[0].to_string());
+        for (idx, operand) in operands[1..operands.len()].iter().enumerate() {
+            match possibility[idx] {
+                0 => equation.push("+".to_string()),
+                1 => equation.push("*".to_string()),
+                2 => equation.push("||".to_string()),
+                _ => panic!("Error in possibility value"),
+            };
+            equation.push(operand.to_string());
+        }
+
+        /* Compute the equation */
+        let mut result:i64 = equation[0].parse().unwrap_or(-1);
+        let mut idx = 1;
+        while idx < equation.len() {
+            match equation[idx].as_str() {
+                "+" => {
+                    result += equation[idx+1].parse().unwrap_or(-1);
+                    idx += 1;
+                },
+                "*" => {
+                    result *= equation[idx+1].parse().unwrap_or(-1);
+                    idx += 1;
+                },
+                "||" => {
+                    let mut number: String = result.to_string();
+                    number.push_str(&equation[idx+1]);
+                    result = number.parse().unwrap_or(-1);
+                    idx += 1;
+                },
+                _ => {},
+            };
+            idx += 1;
+        }
+
+        /* Verify the validity of the equation */
+        if result == expected_result {
+            equation_is_valid = true;
+        }
+
+        /* Update operands possibility */
+        possibility_count += 1;
+        possibility[0] += 1;
+        for idx in 0..possibility.len() {
+            if possibility[idx] == 3 {
+                possibility[idx] = 0;
+                possibility[idx+1] += 1;
+            }
+        }
+     }
+
+     equation_is_valid
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * CREATE LIST OF EQUATIONS
+     */
+    /* Each equation is written as "result: operand operand ...", with non-negative numbers */
+    let equations: Vec<(i64, Vec<i64>)> = ctx.measure("parse", || parse::parse_lines(input, |c| {
+        let result = c.integer_in(0..)?;
+        c.literal_exact(":")?;
+        let operands = c.many(|c| c.integer_in(0..))?;
+
+        Ok((result, operands))
+    })).map_err(|e| format!("Invalid equation: {}", e.annotate(input)))?;
+
+    /*****************************************************
+     * VERIFY EACH EQUATION
+     */
+     let mut valid_equations = 0;
+     let mut calibration = 0;
+     let mut first_equation = 0;
+
+    /* Resume after the equations verified when the run was interrupted */
+    if let Some(checkpoint) = ctx.resumed_checkpoint() {
+        first_equation = checkpoint.require("next_equation")?;
+        valid_equations = checkpoint.require("valid_equations")?;
+        calibration = checkpoint.require("calibration")?;
+    }
+
+    let equation_count = equations.len() as u64;
+    for (eq_idx, (result, mut operands)) in equations.into_iter().enumerate().skip(first_equation) {
+        /* Report the progress, save it and stop if asked to */
+        ctx.progress(eq_idx as u64, equation_count);
+        ctx.checkpoint(|| Checkpoint::new().with("next_equation", eq_idx)
+                                           .with("valid_equations", valid_equations)
+                                           .with("calibration", calibration));
+        ctx.check_cancelled()?;
+
+        /* Test all possible combination of operators for the equation */
+        if equation_is_valid(&mut operands, result) {
+            valid_equations += 1;
+            calibration += result;
+        }
+    }
+
+    Ok(Answer::from(calibration))
+}
@@ -0,0 +1,141 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/8
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use aoc_common::animation::{Colour, Frame, Palette};
+use aoc_common::grid::Grid;
+use aoc_common::inspect::{self, Stat};
+use std::collections::HashMap;
+
+const PALETTE: Palette = &[
+    ('.', Colour::rgb(40, 40, 40)),
+    ('#', Colour::rgb(255, 80, 80)),
+];
+
+#[derive(Debug)]
+struct Antenna {
+    pos_x: i32,
+    pos_y: i32,
+}
+
+fn change_world_cell_value(world: &mut [String], x: &i32, y: &i32, value: &str) {
+        world[*y as usize].replace_range(*x as usize..*x as usize+1, value);
+}
+
+fn get_unique_cell_id(world: &[String], x: &i32, y: &i32) -> i32 {
+    let world_x_len = world[0].len() as i32;
+    let world_y_len = world.len() as i32;
+
+    if *x < world_x_len && *y < world_y_len {
+        *y * world_x_len + *x
+    }
+    else {
+        -1_i32
+    }
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * CREATE MAP OF ANTENNAS
+     */
+    /* Antennas are letters or digits, on a rectangular map */
+    let map = parse::grid(input, "'.', a letter or a digit", |c| c == '.' || c.is_ascii_alphanumeric())
+        .map_err(|e| format!("Invalid map: {}", e.annotate(input)))?;
+    let mut antenna_map: Vec<String> = map.lines.iter().map(|l| l.to_string()).collect();
+
+    /*****************************************************
+     * SEARCH AND LIST ANTENNAS IN THE MAP
+     */
+    let mut antenna_list: HashMap<char, Vec<Antenna>> = HashMap::new();
+    for y in 0..antenna_map.len() {
+        for x in 0..antenna_map[0].len() {
+            let current_cell = antenna_map[y].chars().nth(x).unwrap();
+            if current_cell != '.' {
+                let antenna = Antenna{pos_x: x as i32, pos_y: y as i32};
+
+                match antenna_list.get_mut(&current_cell) { 
+                    None => {
+                        antenna_list.insert(current_cell.to_owned(), vec![antenna]);
+                    },
+                    Some(elt) => {
+                        elt.push(antenna);
+                    },
+                };
+            }
+        }
+    }
+
+    /*****************************************************
+     * COMPUTE ANTINODES FOR EACH TYPE OF ANTENNA
+     */
+     let mut antinodes_list: Vec<i32> = Vec::new();
+     for (freq, antennas) in antenna_list {
+        /* If less than 2 antennas, not antinode possible */
+        if antennas.len() < 2 {
+            continue;
+        }
+
+        /* Compute antinode(s) for each antenna against other antennas */
+        for (idx_ant, antenna) in antennas.iter().enumerate() {
+            for (idx_oant, oantenna) in antennas.iter().enumerate() {
+                /* It's the same antenna, skipping */
+                if idx_ant == idx_oant {
+                    continue;
+                }
+
+                /* Compute distance vector between the two antennas */
+                let dist_x = antenna.pos_x - oantenna.pos_x;
+                let dist_y = antenna.pos_y - oantenna.pos_y;
+
+                /* Compute antinode position with the distance between the two antennas */
+                let anti_node_x = antenna.pos_x + dist_x;
+                let anti_node_y = antenna.pos_y + dist_y;
+
+                /* Count the antinode if it's inside the world and the antinode is at a same place of an antenna */
+                if anti_node_x > -1 && anti_node_x < antenna_map[0].len() as i32 &&
+                   anti_node_y > -1 && anti_node_y < antenna_map.len() as i32 {
+                    
+                    /* If the antinode has not already be pinned, pin it */
+                    let unique_id = get_unique_cell_id(&antenna_map, &anti_node_x, &anti_node_y);
+                    if unique_id != -1 && !antinodes_list.contains(&unique_id) {
+                        antinodes_list.push(unique_id);
+                        let current_cell = antenna_map[anti_node_y as usize].chars().nth(anti_node_x as usize).unwrap();
+                        if current_cell == '.' {
+                            change_world_cell_value(&mut antenna_map, &anti_node_x, &anti_node_y, "#");
+                        }
+
+                        /* Record the new antinode for the animation */
+                        ctx.frame(|| Frame::new(Grid::from_lines(&antenna_map), PALETTE)
+                                        .with_caption(format!("Frequency {freq}: {} antinodes", antinodes_list.len())));
+                    }
+
+
+                }
+            }
+        }
+    }
+
+    ctx.last_frame(|| Frame::new(Grid::from_lines(&antenna_map), PALETTE)
+                          .with_caption(format!("{} antinodes", antinodes_list.len())));
+
+    Ok(Answer::from(antinodes_list.len()))
+}
+
+/* Statistics shown by `aoc inspect`: each pair of antennas of a frequency creates antinodes */
+pub fn inspect(input: &str) -> Result<Vec<Stat>, String> {
+    let map = parse::grid(input, "'.', a letter or a digit", |c| c == '.' || c.is_ascii_alphanumeric())
+        .map_err(|e| format!("Invalid map: {}", e.annotate(input)))?;
+
+    let mut frequencies: HashMap<char, usize> = HashMap::new();
+    for c in map.lines.iter().flat_map(|l| l.chars()).filter(|c| *c != '.') {
+        *frequencies.entry(c).or_insert(0) += 1;
+    }
+
+    Ok(vec![
+        Stat::new("Antennas", frequencies.values().sum::<usize>()),
+        Stat::new("Frequencies", frequencies.len()),
+        Stat::new("Antennas by frequency", inspect::distribution(frequencies.values())),
+        Stat::new("Pairs of antennas", frequencies.values().map(|n| n * (n - 1) / 2).sum::<usize>()),
+    ])
+}
@@ -0,0 +1,156 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/9
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+use aoc_common::animation::{Colour, Frame, Palette};
+use aoc_common::grid::Grid;
+use aoc_common::inspect::Stat;
+
+/* Files are drawn with the last digit of their ID, as in the puzzle */
+const PALETTE: Palette = &[
+    ('.', Colour::rgb(40, 40, 40)),
+    ('0', Colour::rgb(230, 25, 75)),
+    ('1', Colour::rgb(60, 180, 75)),
+    ('2', Colour::rgb(255, 225, 25)),
+    ('3', Colour::rgb(0, 130, 200)),
+    ('4', Colour::rgb(245, 130, 48)),
+    ('5', Colour::rgb(145, 30, 180)),
+    ('6', Colour::rgb(70, 240, 240)),
+    ('7', Colour::rgb(240, 50, 230)),
+    ('8', Colour::rgb(210, 245, 60)),
+    ('9', Colour::rgb(250, 190, 212)),
+];
+
+/* Long disks are wrapped to be drawn as a square */
+fn disk_frame(disk_data: &[i32], caption: String) -> Frame {
+    let width = match disk_data.len() {
+        0..=100 => disk_data.len().max(1),
+        len => len.isqrt() + 1,
+    };
+    let lines: Vec<String> = disk_data.chunks(width)
+                                      .map(|blocks| blocks.iter().map(|b| match *b {
+                                          -1 => '.',
+                                          id => char::from_digit(id as u32 % 10, 10).unwrap(),
+                                      }).collect())
+                                      .collect();
+
+    Frame::new(Grid::from_lines(&lines), PALETTE).with_caption(caption)
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * READ DISK MAP FROM FILE
+     */
+    /* The disk map is a single line of digits */
+    let disk_map = parse::single_line(input, "a digit", |c| c.is_ascii_digit())
+        .map_err(|e| format!("Invalid disk map: {}", e.annotate(input)))?;
+
+    /*****************************************************
+     * CONSTRUCT THE DISK DATA USING THE DISK MAP
+     */
+     /* Note: I've decided to store the disk file ID as i32 and -1 means free-space */
+     let mut file_id: i32 = 0;
+     let mut disk_data: Vec<i32> = Vec::new();
+     for (idx, disk_entry) in disk_map.lines[0].chars().enumerate() {
+        let block_count = disk_entry as i32 - '0' as i32;
+        if idx % 2 == 0 {
+            /* Add File blocks */
+            for _ in 0..block_count {
+                disk_data.push(file_id);
+            }
+            file_id += 1;
+        }
+        else {
+            /* Add Free space blocks */
+            for _ in 0..block_count {
+                disk_data.push(-1);
+            }
+        }
+     }
+
+     ctx.last_frame(|| disk_frame(&disk_data, "Initial disk".to_string()));
+
+    /*****************************************************
+     * COMPACT ALL THE FILES TO THE BEGINING OF THE DISK
+     */
+    /* Start reading at the end of the disk */
+    let mut read_idx = (disk_data.len() - 1) as i32;
+    /* Start writting at the begining of the disk */
+    let mut write_idx = 0;
+    let mut value = -1;
+
+    /* Until all the block data has been moved */
+    while read_idx >= write_idx {
+        /* If no block data is currently moving */
+        if value == -1 {
+            /* If a block data (non free-space) is detected */
+            if disk_data[read_idx as usize] != -1 {
+                /* Temporarely store the block data */
+                value = disk_data[read_idx as usize];
+                /* Mark the current block data location as free-space */
+                disk_data[read_idx as usize] = -1;
+            }
+            /* Move read index one block to the left */
+            read_idx -= 1;
+        }
+        else {
+            /* If the current block for writting is empty space */
+            if disk_data[write_idx as usize] == -1 {
+                /* Store the block to be moved in the new location */
+                disk_data[write_idx as usize] = value;
+                /* Clear the temporary storage */ 
+                value = -1;
+
+                /* Record the move for the animation */
+                ctx.frame(|| disk_frame(&disk_data, format!("Block moved to {write_idx}")));
+            }
+            /* Move read index one block to the right */
+            write_idx += 1;
+        }
+    }
+
+    /* If a value is currently moving, find first empty block and store the data */
+    if value != -1 {
+        while (write_idx as usize) < disk_data.len() && disk_data[write_idx as usize] != -1 {
+            write_idx += 1;
+        }
+        if (write_idx as usize) < disk_data.len() && disk_data[write_idx as usize] == -1 {
+            disk_data[write_idx as usize] = value;
+        }
+        else {
+            return Err("Could not store last block of data !".to_string());
+        }
+    }
+
+    ctx.last_frame(|| disk_frame(&disk_data, "Compacted disk".to_string()));
+
+    /*****************************************************
+     * COMPUTE THE CHECKSUM OF THE DISK
+     */
+    let mut checksum: i64 = 0;
+    for (blk_id, blk_data) in disk_data.iter().enumerate() {
+        if *blk_data > -1 {
+            checksum += blk_id as i64 * *blk_data as i64
+        }
+    }
+
+    Ok(Answer::from(checksum))
+}
+
+/* Statistics shown by `aoc inspect`: the digits alternate between files and free spaces */
+pub fn inspect(input: &str) -> Result<Vec<Stat>, String> {
+    let disk_map = parse::single_line(input, "a digit", |c| c.is_ascii_digit())
+        .map_err(|e| format!("Invalid disk map: {}", e.annotate(input)))?;
+    let sizes: Vec<usize> = disk_map.lines[0].chars().map(|c| c as usize - '0' as usize).collect();
+    let file_blocks: usize = sizes.iter().step_by(2).sum();
+    let free_blocks: usize = sizes.iter().skip(1).step_by(2).sum();
+
+    Ok(vec![
+        Stat::new("Files", sizes.len().div_ceil(2)),
+        Stat::new("Total disk blocks", file_blocks + free_blocks),
+        Stat::new("File blocks", file_blocks),
+        Stat::new("Free blocks", free_blocks),
+        Stat::new("Empty files", sizes.iter().step_by(2).filter(|s| **s == 0).count()),
+    ])
+}
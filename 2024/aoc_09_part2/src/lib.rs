@@ -0,0 +1,150 @@
+/* ADVENT OF CODE
+ * See: https://adventofcode.com/2024/day/9#part2
+ */
+use aoc_common::{Answer, Context};
+use aoc_common::parse;
+
+#[derive(Debug, Clone, Copy)]
+struct DiskEntry {
+    idx: usize,
+    block_count: usize,
+    is_file: bool
+}
+
+fn search_place_for_disk_entry(list: &[DiskEntry], size: usize) -> Option<usize> {
+    for (idx, entry) in list.iter().enumerate() {
+        if !entry.is_file && entry.block_count >= size {
+            return Some(idx);
+        }
+    }
+
+    None
+}
+
+fn move_disk_entry(list: &mut Vec<DiskEntry>, from: usize, to: usize) {
+    // TODO Add verifications
+
+    /* First, we clone the entry to be moved from the Vec */
+    let data_entry = list[from];
+
+    /* Then, we replace the entry to be moved by free-space */
+    list[from].idx = usize::MAX;
+    list[from].is_file = false;
+
+    /* If data is exactly the size of free-space */
+    if data_entry.block_count == list[to].block_count {
+        /* Replace free-space by data */
+        list[to] = data_entry;
+    }
+    else {
+        /* Reduce the size of free-space */
+        list[to].block_count -= data_entry.block_count;
+
+        /* Insert the data before the remaining free-space */
+        list.insert(to, data_entry);
+    }
+}
+
+pub fn solve(input: &str, ctx: &Context) -> Result<Answer, String> {
+    /*****************************************************
+     * READ DISK MAP FROM FILE
+     */
+    /* The disk map is a single line of digits */
+    let disk_map = parse::single_line(input, "a digit", |c| c.is_ascii_digit())
+        .map_err(|e| format!("Invalid disk map: {}", e.annotate(input)))?;
+
+    /*****************************************************
+     * CONSTRUCT THE DISK DATA USING THE DISK MAP
+     */
+     /* Note: I've decided to store the disk file ID as i32 and -1 means free-space */
+    let mut file_id: i32 = 0;
+    let mut disk_data: Vec<i32> = Vec::new();
+    let mut files: Vec<DiskEntry> = Vec::new();
+    for (idx, disk_entry) in disk_map.lines[0].chars().enumerate() {
+        let block_count = disk_entry as i32 - '0' as i32;
+        let is_file;
+        if idx % 2 == 0 {
+            /* Add File blocks */
+            is_file = true;
+            for _ in 0..block_count {
+                disk_data.push(file_id);
+            }
+
+            /* Store disk information */
+            files.push(DiskEntry{idx: file_id as usize, block_count: block_count as usize, is_file});
+
+            /* Increment file identifier */
+            file_id += 1;
+        }
+        else {
+            /* Add Free-space blocks to disk map */
+            is_file = false;
+            for _ in 0..block_count {
+                disk_data.push(-1);
+            }
+
+            /* Store disk information */
+            files.push(DiskEntry{idx: usize::MAX, block_count: block_count as usize, is_file});
+        }
+    }
+
+    /* Store the last file id for future use */
+    let last_file_id: i32 = file_id - 1;
+
+    /*****************************************************
+     * COMPACT ALL THE FILES TO THE BEGINING OF THE DISK
+     */
+    /* Start from the last file in the disk to the second one (first one is already at the start of the disk) */
+    let mut file_id: i32 = last_file_id;
+    let mut read_idx: i32;
+    while file_id > 1 {
+        /* Report the progress and stop if asked to */
+        ctx.progress((last_file_id - file_id) as u64, last_file_id as u64);
+        ctx.check_cancelled()?;
+
+        /* Find the file with current file ID in the disk */
+        read_idx = (files.len() - 1) as i32;
+        while read_idx > -1 {
+            if files[read_idx as usize].is_file &&
+               files[read_idx as usize].idx == file_id as usize {
+                break;
+            }
+            read_idx -= 1;
+        }
+
+        /* If the file with specified ID has been found */
+        if read_idx > -1 {
+            /* Look for a place where to store the file, before it on the disk */
+            if let Some(idx) = search_place_for_disk_entry(&files, files[read_idx as usize].block_count)
+                && idx < read_idx as usize {
+                    /* Move the file at the new location */
+                    move_disk_entry(&mut files, read_idx as usize, idx);
+                }
+        }
+        else {
+            panic!("BUG: file_id not found !");
+        }
+        
+        /* Going to next file */
+        file_id -= 1;
+    }
+
+    /*****************************************************
+     * COMPUTE THE CHECKSUM OF THE DISK
+     */
+    let mut checksum = 0_i64;
+    let mut idx: usize = 0;
+
+    for e in &files {
+        if e.is_file {
+            for _ in 0..e.block_count {
+                checksum += (idx * e.idx) as i64;
+                idx += 1;
+            }
+        }
+        else {
+            idx += e.block_count;
+        }
+    }
+    Ok(Answer::from(checksum))
+}
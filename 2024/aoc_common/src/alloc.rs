@@ -0,0 +1,191 @@
+/* Counting allocator, to find the solvers which allocate the most.
+ *
+ * It becomes the global allocator of the binaries with the `count-allocations` feature, e.g.
+ * `cargo run --features count-allocations -- run --day 10` for `aoc`, or
+ * `cargo run --features aoc_common/count-allocations` for the binary of a day.
+ *
+ * The counters are shared by all the threads, so a measure includes the allocations made by the
+ * other threads meanwhile. Its peak is the one of the heap: the resident memory of the process,
+ * which also holds its code and its stacks, is given by `peak_rss` for the whole process only.
+ */
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static IN_USE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocator of the system, counting the allocations and the memory in use.
+pub struct CountingAllocator;
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let in_use = IN_USE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    /* A reallocation counts as an allocation of the new size */
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            IN_USE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Whether the allocations are counted, i.e. the `count-allocations` feature is enabled.
+pub fn is_enabled() -> bool {
+    cfg!(feature = "count-allocations")
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Highest heap memory in use during the measure, including the one allocated before it
+    pub peak_bytes: usize,
+}
+
+impl AllocStats {
+    /// Adds the measure of another run of the same code.
+    pub fn add(&mut self, other: &AllocStats) {
+        self.allocations += other.allocations;
+        self.allocated_bytes += other.allocated_bytes;
+        self.peak_bytes = self.peak_bytes.max(other.peak_bytes);
+    }
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} allocations, {} allocated, peak heap {}", self.allocations,
+               format_bytes(self.allocated_bytes), format_bytes(self.peak_bytes as u64))
+    }
+}
+
+/// Highest resident memory of the process since it started, from `getrusage` on Unix.
+#[cfg(unix)]
+pub fn peak_rss() -> Option<u64> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+
+    /* In bytes on macOS, in KiB elsewhere */
+    let max_rss = usage.ru_maxrss as u64;
+    Some(if cfg!(target_os = "macos") { max_rss } else { max_rss * 1024 })
+}
+
+#[cfg(not(unix))]
+pub fn peak_rss() -> Option<u64> {
+    None
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Measure in progress, started by `Measure::start` and ended by `Measure::finish`.
+pub struct Measure {
+    allocations: u64,
+    allocated_bytes: u64,
+    /// Peak of the enclosing measure, restored at the end of this one
+    outer_peak: usize,
+}
+
+impl Measure {
+    pub fn start() -> Self {
+        let outer_peak = PEAK.swap(IN_USE.load(Ordering::Relaxed), Ordering::Relaxed);
+        Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            outer_peak,
+        }
+    }
+
+    pub fn finish(self) -> AllocStats {
+        let peak_bytes = PEAK.fetch_max(self.outer_peak, Ordering::Relaxed);
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - self.allocated_bytes,
+            peak_bytes,
+        }
+    }
+}
+
+/// Measures the allocations made by `f`, e.g. to compare the parsing with the rest of a solver.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, AllocStats) {
+    let measure = Measure::start();
+    let result = f();
+    (result, measure.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+        assert_eq!(format_bytes(u64::MAX), "16777216.0 TiB");
+    }
+
+    #[test]
+    fn adds_runs() {
+        let mut stats = AllocStats { allocations: 2, allocated_bytes: 100, peak_bytes: 80 };
+        stats.add(&AllocStats { allocations: 3, allocated_bytes: 50, peak_bytes: 60 });
+
+        assert_eq!(stats, AllocStats { allocations: 5, allocated_bytes: 150, peak_bytes: 80 });
+        assert_eq!(stats.to_string(), "5 allocations, 150 B allocated, peak heap 80 B");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn measures_the_resident_memory() {
+        assert!(peak_rss().is_some_and(|rss| rss > 0));
+    }
+}
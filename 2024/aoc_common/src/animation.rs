@@ -0,0 +1,311 @@
+/* Animation of the grid simulations.
+ *
+ * A solver records snapshots of its grid with `Context::frame`, which are collected by the
+ * runner and played once the part is done. Playback happens in the terminal with colours given
+ * by the palette of each frame, or is written to a file in headless mode.
+ *
+ * Keys during playback: space pauses, right/left (or n/b) steps, +/- changes the speed and q quits.
+ */
+use crate::args::Args;
+use crate::grid::Grid;
+use std::fs::File;
+use std::io::{BufWriter, IsTerminal, Write};
+use std::path::PathBuf;
+
+/// Number of frames kept in memory, beyond which only one frame out of two is kept.
+const MAX_FRAMES: usize = 1000;
+
+pub const DEFAULT_FPS: f64 = 10.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Colour {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Colour {
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+}
+
+/// Colour of each character of a grid, the other ones using the default colour.
+pub type Palette = &'static [(char, Colour)];
+
+pub fn colour_of(palette: Palette, c: char) -> Option<Colour> {
+    palette.iter().find(|(p, _)| *p == c).map(|(_, colour)| *colour)
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frame {
+    pub grid: Grid<char>,
+    pub palette: Palette,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(grid: Grid<char>, palette: Palette) -> Self {
+        Self { grid, palette, caption: String::new() }
+    }
+
+    pub fn with_caption(mut self, caption: impl Into<String>) -> Self {
+        self.caption = caption.into();
+        self
+    }
+}
+
+/// Collects the frames of a run, dropping one frame out of two each time there are too many. The
+/// first frame is kept, and so are the frames recorded with `always`.
+#[derive(Debug, Default)]
+pub(crate) struct FrameRecorder {
+    /// Frames, and whether they were recorded with `always`
+    frames: Vec<(Frame, bool)>,
+    stride: usize,
+    seen: usize,
+}
+
+impl FrameRecorder {
+    pub(crate) fn new() -> Self {
+        Self { frames: Vec::new(), stride: 1, seen: 0 }
+    }
+
+    pub(crate) fn record<F: FnOnce() -> Frame>(&mut self, build: F, always: bool) {
+        self.seen += 1;
+        if !always && !(self.seen - 1).is_multiple_of(self.stride) {
+            return;
+        }
+
+        self.frames.push((build(), always));
+        if self.frames.len() > MAX_FRAMES {
+            let mut idx = 0;
+            self.frames.retain(|(_, always)| {
+                idx += 1;
+                idx % 2 == 1 || *always
+            });
+            self.stride *= 2;
+        }
+    }
+
+    pub(crate) fn take(&mut self) -> Vec<Frame> {
+        std::mem::take(&mut self.frames).into_iter().map(|(frame, _)| frame).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlaybackOptions {
+    pub fps: f64,
+    /// Start paused, to go through the frames one by one
+    pub step: bool,
+    /// Write the frames to this file instead of playing them
+    pub output: Option<PathBuf>,
+}
+
+impl PlaybackOptions {
+    /// Reads `--animate`, `--fps <N>`, `--step` and `--frames-out <FILE>`. Returns `None` when
+    /// no animation was asked for.
+    pub fn from_args(args: &Args) -> Result<Option<Self>, String> {
+        let output = args.value("--frames-out").map(PathBuf::from);
+        let step = args.flag("--step");
+        if !args.flag("--animate") && !step && output.is_none() {
+            return Ok(None);
+        }
+
+        let fps = args.parsed_value("--fps")?.unwrap_or(DEFAULT_FPS);
+        if !(fps > 0.0 && fps.is_finite()) {
+            return Err(format!("Invalid value '{fps}' for option '--fps'"));
+        }
+
+        Ok(Some(Self { fps, step, output }))
+    }
+}
+
+/// Plays the frames in the terminal, or writes them to the output file of the options.
+pub fn play(frames: &[Frame], options: &PlaybackOptions) -> Result<(), String> {
+    if let Some(path) = &options.output {
+        return write_frames(frames, path).map_err(|e| format!("Could not write '{}': {e}", path.display()));
+    }
+    if frames.is_empty() {
+        return Err("No frame to play".to_string());
+    }
+    if !std::io::stdout().is_terminal() {
+        return Err("Playing an animation needs a terminal, use '--frames-out <FILE>' instead".to_string());
+    }
+
+    playback::play(frames, options)
+}
+
+/* Playback in the terminal, which does not exist on WebAssembly */
+#[cfg(not(target_arch = "wasm32"))]
+mod playback {
+    use super::{colour_of, Colour, Frame, PlaybackOptions};
+    use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+    use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+    use crossterm::{cursor, execute, queue, terminal};
+    use std::io::Write;
+    use std::time::Duration;
+
+    pub(super) fn play(frames: &[Frame], options: &PlaybackOptions) -> Result<(), String> {
+        terminal::enable_raw_mode().map_err(|e| e.to_string())?;
+        let mut stdout = std::io::stdout();
+        let _ = execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide, terminal::Clear(terminal::ClearType::All));
+
+        let result = play_interactive(frames, options);
+
+        let _ = execute!(stdout, cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+        result.map_err(|e| e.to_string())
+    }
+
+    fn play_interactive(frames: &[Frame], options: &PlaybackOptions) -> std::io::Result<()> {
+        let mut stdout = std::io::stdout();
+        let mut fps = options.fps;
+        let mut paused = options.step;
+        let mut idx = 0;
+
+        loop {
+            let state = if paused { "paused" } else { "playing" };
+            draw(&mut stdout, &frames[idx], &format!("Frame {}/{} - {fps} fps - {state}", idx + 1, frames.len()))?;
+
+            /* Wait for the next frame, or for a key when paused or at the end */
+            let wait = if paused || idx + 1 == frames.len() { None } else { Some(Duration::from_secs_f64(1.0 / fps)) };
+            let key = match wait {
+                Some(delay) if !event::poll(delay)? => None,
+                _ => match event::read()? {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => Some(key.code),
+                    _ => continue,
+                },
+            };
+
+            match key {
+                None => idx += 1,
+                Some(KeyCode::Char('q')) | Some(KeyCode::Esc) => return Ok(()),
+                Some(KeyCode::Char(' ')) | Some(KeyCode::Char('p')) => paused = !paused,
+                Some(KeyCode::Right) | Some(KeyCode::Char('n')) => {
+                    paused = true;
+                    idx = (idx + 1).min(frames.len() - 1);
+                },
+                Some(KeyCode::Left) | Some(KeyCode::Char('b')) => {
+                    paused = true;
+                    idx = idx.saturating_sub(1);
+                },
+                Some(KeyCode::Char('+')) => fps *= 2.0,
+                Some(KeyCode::Char('-')) => fps /= 2.0,
+                Some(KeyCode::Home) => idx = 0,
+                Some(KeyCode::End) => idx = frames.len() - 1,
+                Some(_) => {},
+            }
+        }
+    }
+
+    fn draw(out: &mut impl Write, frame: &Frame, status: &str) -> std::io::Result<()> {
+        for (y, row) in frame.grid.rows().enumerate() {
+            queue!(out, cursor::MoveTo(0, y as u16))?;
+            let mut current = None;
+
+            for &c in row {
+                let colour = colour_of(frame.palette, c);
+                if colour != current {
+                    match colour {
+                        Some(Colour { r, g, b }) => queue!(out, SetForegroundColor(Color::Rgb { r, g, b }))?,
+                        None => queue!(out, ResetColor)?,
+                    }
+                    current = colour;
+                }
+                queue!(out, Print(c))?;
+            }
+            queue!(out, ResetColor)?;
+        }
+
+        let y = frame.grid.height() as u16;
+        /* The frames of an animation have the same size, only the texts below need to be cleared */
+        queue!(out, cursor::MoveTo(0, y), Print(&frame.caption), terminal::Clear(terminal::ClearType::UntilNewLine))?;
+        queue!(out, cursor::MoveTo(0, y + 1), Print(status), terminal::Clear(terminal::ClearType::UntilNewLine))?;
+        out.flush()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod playback {
+    use super::{Frame, PlaybackOptions};
+
+    pub(super) fn play(_frames: &[Frame], _options: &PlaybackOptions) -> Result<(), String> {
+        Err("Playing an animation needs a terminal, use '--frames-out <FILE>' instead".to_string())
+    }
+}
+
+/// Headless mode: writes every frame as text, after a line with its number and caption.
+pub fn write_frames(frames: &[Frame], path: &std::path::Path) -> std::io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+
+    for (idx, frame) in frames.iter().enumerate() {
+        writeln!(out, "Frame {}/{}: {}", idx + 1, frames.len(), frame.caption)?;
+        write!(out, "{}", frame.grid)?;
+        writeln!(out)?;
+    }
+
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(caption: String) -> Frame {
+        Frame::new(Grid::from_lines(&["#"]), &[]).with_caption(caption)
+    }
+
+    fn captions(recorder: &mut FrameRecorder) -> Vec<String> {
+        recorder.take().into_iter().map(|f| f.caption).collect()
+    }
+
+    #[test]
+    fn keeps_every_frame_up_to_the_limit() {
+        let mut recorder = FrameRecorder::new();
+        for idx in 0..MAX_FRAMES {
+            recorder.record(|| frame(idx.to_string()), false);
+        }
+
+        let expected: Vec<String> = (0..MAX_FRAMES).map(|idx| idx.to_string()).collect();
+        assert_eq!(captions(&mut recorder), expected);
+    }
+
+    #[test]
+    fn decimates_beyond_the_limit() {
+        let mut recorder = FrameRecorder::new();
+        for idx in 0..=MAX_FRAMES {
+            recorder.record(|| frame(idx.to_string()), false);
+        }
+        assert_eq!((recorder.frames.len(), recorder.stride), (MAX_FRAMES / 2 + 1, 2));
+
+        /* The frames are then skipped with the doubled stride, until the next decimation */
+        for idx in MAX_FRAMES + 1..3 * MAX_FRAMES {
+            recorder.record(|| frame(idx.to_string()), false);
+        }
+        assert_eq!(recorder.stride, 4);
+
+        let captions = captions(&mut recorder);
+        let numbers: Vec<usize> = captions.iter().map(|c| c.parse().unwrap()).collect();
+        assert!(numbers.len() <= MAX_FRAMES, "{}", numbers.len());
+        assert_eq!(numbers[..4], [0, 4, 8, 12]);
+        assert!(numbers.windows(2).all(|w| w[1] - w[0] == 4), "{numbers:?}");
+    }
+
+    #[test]
+    fn keeps_the_first_last_and_forced_frames() {
+        let mut recorder = FrameRecorder::new();
+        recorder.record(|| frame("Initial".to_string()), true);
+        for idx in 0..5 * MAX_FRAMES {
+            let forced = idx % 1000 == 333;
+            recorder.record(|| frame(format!("{}{idx}", if forced { "Forced " } else { "" })), forced);
+        }
+        recorder.record(|| frame("Final".to_string()), true);
+
+        let captions = captions(&mut recorder);
+        assert!(captions.len() <= MAX_FRAMES, "{}", captions.len());
+        assert_eq!(captions.first().map(String::as_str), Some("Initial"));
+        assert_eq!(captions.last().map(String::as_str), Some("Final"));
+        let forced: Vec<&str> = captions.iter().filter(|c| c.starts_with("Forced")).map(String::as_str).collect();
+        assert_eq!(forced, ["Forced 333", "Forced 1333", "Forced 2333", "Forced 3333", "Forced 4333"]);
+    }
+}
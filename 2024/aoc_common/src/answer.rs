@@ -0,0 +1,228 @@
+/* Answer given by a solver for a part of a puzzle.
+ *
+ * Integer answers are compared by value whatever their type, so an answer computed as an `u64`
+ * matches the same value written in a file of expected answers. Other answers are compared
+ * through their displayed form, without the trailing whitespace of each line as editors tend to
+ * add or remove it.
+ */
+use num_bigint::BigInt;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    I128(i128),
+    U128(u128),
+    Big(BigInt),
+    Str(String),
+    /// Multi-line answer, e.g. letters drawn in a grid
+    Text(String),
+}
+
+impl Answer {
+    /// Builds an answer from its textual form, e.g. read from a file of expected answers.
+    pub fn parse(text: &str) -> Self {
+        let text = text.trim_end();
+
+        if text.contains('\n') {
+            return Answer::Text(text.to_string());
+        }
+
+        let text = text.trim();
+        if let Ok(v) = text.parse::<i64>() {
+            Answer::I64(v)
+        }
+        else if let Ok(v) = text.parse::<u64>() {
+            Answer::U64(v)
+        }
+        else if let Ok(v) = text.parse::<i128>() {
+            Answer::I128(v)
+        }
+        else if let Ok(v) = text.parse::<u128>() {
+            Answer::U128(v)
+        }
+        else if let Ok(v) = text.parse::<BigInt>() {
+            Answer::Big(v)
+        }
+        else {
+            Answer::Str(text.to_string())
+        }
+    }
+
+    /// Verifies the answer against its expected textual form.
+    pub fn matches(&self, expected: &str) -> bool {
+        *self == Answer::parse(expected)
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.to_bigint().is_some()
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(_))
+    }
+
+    /// Name of the variant, so that an answer stored as text is read back the same with `from_kind`:
+    /// the string `007` is not the integer 7.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::I64(_) => "i64",
+            Answer::U64(_) => "u64",
+            Answer::I128(_) => "i128",
+            Answer::U128(_) => "u128",
+            Answer::Big(_) => "big",
+            Answer::Str(_) => "str",
+            Answer::Text(_) => "text",
+        }
+    }
+
+    /// Answer of the variant named `kind` from its displayed form.
+    pub fn from_kind(kind: &str, text: &str) -> Option<Self> {
+        match kind {
+            "i64" => text.parse().ok().map(Answer::I64),
+            "u64" => text.parse().ok().map(Answer::U64),
+            "i128" => text.parse().ok().map(Answer::I128),
+            "u128" => text.parse().ok().map(Answer::U128),
+            "big" => text.parse().ok().map(Answer::Big),
+            "str" => Some(Answer::Str(text.to_string())),
+            "text" => Some(Answer::Text(text.to_string())),
+            _ => None,
+        }
+    }
+
+    pub fn to_bigint(&self) -> Option<BigInt> {
+        match self {
+            Answer::I64(v) => Some(BigInt::from(*v)),
+            Answer::U64(v) => Some(BigInt::from(*v)),
+            Answer::I128(v) => Some(BigInt::from(*v)),
+            Answer::U128(v) => Some(BigInt::from(*v)),
+            Answer::Big(v) => Some(v.clone()),
+            Answer::Str(_) | Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        if let (Some(a), Some(b)) = (self.to_bigint(), other.to_bigint()) {
+            return a == b;
+        }
+
+        /* Otherwise compare what would be displayed, line by line */
+        let a = self.to_string();
+        let b = other.to_string();
+        a.lines().map(str::trim_end).eq(b.lines().map(str::trim_end))
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::I64(v) => write!(f, "{v}"),
+            Answer::U64(v) => write!(f, "{v}"),
+            Answer::I128(v) => write!(f, "{v}"),
+            Answer::U128(v) => write!(f, "{v}"),
+            Answer::Big(v) => write!(f, "{v}"),
+            Answer::Str(v) => write!(f, "{}", v.trim()),
+            Answer::Text(v) => write!(f, "{}", v.trim_end()),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($($t:ty => $variant:ident),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(i32 => I64, i64 => I64, u32 => U64, u64 => U64, i128 => I128, u128 => U128,
+             BigInt => Big, String => Str, &str => Str);
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::U64(value as u64)
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::I64(value as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_smallest_variant() {
+        assert!(matches!(Answer::parse(" -42\n"), Answer::I64(-42)));
+        assert!(matches!(Answer::parse("18446744073709551615"), Answer::U64(u64::MAX)));
+        assert!(matches!(Answer::parse("-18446744073709551616"), Answer::I128(_)));
+        assert!(matches!(Answer::parse("340282366920938463463374607431768211455"), Answer::U128(u128::MAX)));
+        assert!(matches!(Answer::parse("340282366920938463463374607431768211456"), Answer::Big(_)));
+        assert!(matches!(Answer::parse(" abc,def "), Answer::Str(ref s) if s == "abc,def"));
+        assert!(matches!(Answer::parse("#.#\n.#.\n\n"), Answer::Text(ref s) if s == "#.#\n.#."));
+    }
+
+    #[test]
+    fn integers_compare_across_variants() {
+        assert_eq!(Answer::from(42u64), Answer::from(42i32));
+        assert_eq!(Answer::from(42usize), Answer::I128(42));
+        assert_eq!(Answer::U128(7), Answer::Big(BigInt::from(7)));
+        assert_ne!(Answer::from(-1i64), Answer::from(u64::MAX));
+        assert_ne!(Answer::from(42), Answer::from(43));
+
+        assert!(Answer::from(u64::MAX).matches("18446744073709551615"));
+        assert!(Answer::Big(BigInt::from(5)).matches("5\n"));
+        assert!(!Answer::from(5).matches("5.0"));
+    }
+
+    #[test]
+    fn text_compares_trimmed_lines() {
+        let text = Answer::Text("#..#  \n#..#\n".to_string());
+
+        assert!(text.matches("#..#\n#..#   \n\n"));
+        assert!(!text.matches("#..#\n#...\n"));
+        assert!(!text.matches(" #..#\n#..#"));
+        assert!(!text.matches("#..#"));
+
+        assert!(Answer::from("abc").matches("  abc\n"));
+        assert!(!Answer::from("abc").matches("ABC"));
+        assert_eq!(Answer::from("12"), Answer::from(12));
+    }
+
+    #[test]
+    fn kinds() {
+        assert!(Answer::from(1u128).is_integer());
+        assert!(!Answer::from("x").is_integer());
+        assert!(Answer::parse("a\nb").is_multiline());
+        assert!(!Answer::parse("ab").is_multiline());
+        assert_eq!(Answer::from(-3isize).to_bigint(), Some(BigInt::from(-3)));
+    }
+
+    #[test]
+    fn kinds_round_trip() {
+        let answers = [Answer::I64(-5), Answer::U64(u64::MAX), Answer::I128(i128::MIN), Answer::U128(u128::MAX),
+                       Answer::Big(BigInt::from(u128::MAX) * 10), Answer::from("007"), Answer::Text("#.\n.#".to_string())];
+
+        for answer in answers {
+            let read = Answer::from_kind(answer.kind(), &answer.to_string()).unwrap();
+            assert_eq!(read.kind(), answer.kind());
+            assert_eq!(read.to_string(), answer.to_string());
+        }
+
+        assert!(matches!(Answer::from_kind("str", "007"), Some(Answer::Str(ref s)) if s == "007"));
+        assert_eq!(Answer::from_kind("u64", "-1"), None);
+        assert_eq!(Answer::from_kind("float", "1.5"), None);
+    }
+}
@@ -0,0 +1,182 @@
+/* Minimal parser for the command line arguments of the binaries.
+ *
+ * Options are written `--name value` or `--name=value`, flags are options without value. The
+ * options which take a value must be declared as `value_options` so that `--name value` is not
+ * mistaken for a flag followed by a positional argument.
+ */
+use std::collections::HashMap;
+use std::time::Duration;
+
+#[derive(Clone, Debug, Default)]
+pub struct Args {
+    pub positional: Vec<String>,
+    options: HashMap<String, Vec<String>>,
+    flags: Vec<String>,
+}
+
+impl Args {
+    pub fn parse<I>(args: I, value_options: &[&str]) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut result = Args::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            if let Some((name, value)) = arg.split_once('=').filter(|_| arg.starts_with("--")) {
+                result.options.entry(name.to_string()).or_default().push(value.to_string());
+            }
+            else if value_options.contains(&arg.as_str()) {
+                match args.next() {
+                    Some(value) => result.options.entry(arg).or_default().push(value),
+                    None => return Err(format!("Missing value for option '{arg}'")),
+                }
+            }
+            else if arg.starts_with("--") {
+                result.flags.push(arg);
+            }
+            else {
+                result.positional.push(arg);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Parses the arguments given to the current process.
+    pub fn from_env(value_options: &[&str]) -> Result<Self, String> {
+        Self::parse(std::env::args().skip(1), value_options)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|f| f == name)
+    }
+
+    /// Last value given for the option
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|v| v.last()).map(|v| v.as_str())
+    }
+
+    /// All the values given for an option which can be repeated
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.options.get(name).map(|v| v.iter().map(|s| s.as_str()).collect()).unwrap_or_default()
+    }
+
+    pub fn parsed_value<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.value(name) {
+            Some(v) => v.parse().map(Some).map_err(|_| format!("Invalid value '{v}' for option '{name}'")),
+            None => Ok(None),
+        }
+    }
+
+    pub fn duration(&self, name: &str) -> Result<Option<Duration>, String> {
+        match self.value(name) {
+            Some(v) => parse_duration(v).map(Some).ok_or(format!("Invalid duration '{v}' for option '{name}'")),
+            None => Ok(None),
+        }
+    }
+
+    /// Size in bytes, see `parse_size`.
+    pub fn size(&self, name: &str) -> Result<Option<u64>, String> {
+        match self.value(name) {
+            Some(v) => parse_size(v).map(Some).ok_or(format!("Invalid size '{v}' for option '{name}'")),
+            None => Ok(None),
+        }
+    }
+
+    /// Flags which are not part of `known`, to report typos to the user.
+    pub fn unknown_flags(&self, known: &[&str]) -> Vec<&str> {
+        self.flags.iter().map(|f| f.as_str()).filter(|f| !known.contains(f)).collect()
+    }
+}
+
+/// Parses a duration such as `1.5`, `1.5s`, `200ms` or `2m`. Plain numbers are seconds.
+pub fn parse_duration(text: &str) -> Option<Duration> {
+    let text = text.trim();
+    let (number, unit) = match text.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) => text.split_at(idx),
+        None => (text, "s"),
+    };
+
+    let value: f64 = number.parse().ok()?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "s" => value,
+        "m" => value * 60.0,
+        _ => return None,
+    };
+
+    /* Negative, infinite and too long durations are refused */
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+/// Parses a size such as `4096`, `512K`, `512M` or `2G`, in powers of 1024. Plain numbers are bytes.
+pub fn parse_size(text: &str) -> Option<u64> {
+    let text = text.trim();
+    let (number, unit) = match text.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) => text.split_at(idx),
+        None => (text, ""),
+    };
+
+    let value: f64 = number.parse().ok()?;
+    let multiplier: u64 = match unit.to_ascii_uppercase().trim_end_matches("IB").trim_end_matches('B') {
+        "" => 1,
+        "K" => 1 << 10,
+        "M" => 1 << 20,
+        "G" => 1 << 30,
+        _ => return None,
+    };
+
+    /* The cast saturates, so the sizes which do not fit are refused before it. 2^64 is exact */
+    let bytes = value * multiplier as f64;
+    match bytes.is_finite() && (0.0..u64::MAX as f64).contains(&bytes) {
+        true => Some(bytes as u64),
+        false => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Args {
+        Args::parse(args.iter().map(|a| a.to_string()), &["--timeout", "--tag"]).unwrap()
+    }
+
+    #[test]
+    fn options_flags_and_positional() {
+        let args = args(&["input.txt", "--timeout", "2s", "--resume", "--tag=grid", "--tag", "sorting"]);
+
+        assert_eq!(args.positional, ["input.txt"]);
+        assert_eq!(args.duration("--timeout"), Ok(Some(Duration::from_secs(2))));
+        assert_eq!(args.values("--tag"), ["grid", "sorting"]);
+        assert_eq!(args.value("--tag"), Some("sorting"));
+        assert!(args.flag("--resume"));
+        assert_eq!(args.unknown_flags(&["--test"]), ["--resume"]);
+        assert!(Args::parse(["--timeout".to_string()], &["--timeout"]).is_err());
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(parse_duration("1.5"), Some(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("200ms"), Some(Duration::from_millis(200)));
+        assert_eq!(parse_duration(" 2m "), Some(Duration::from_secs(120)));
+
+        for invalid in ["", "-1s", "2h", "inf", "NaN", "1e30", "1000000000000000000000000000000"] {
+            assert_eq!(parse_duration(invalid), None, "{invalid}");
+        }
+        assert!(args(&["--timeout", "1000000000000000000000000000000"]).duration("--timeout").is_err());
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(parse_size("4096"), Some(4096));
+        assert_eq!(parse_size("512K"), Some(512 << 10));
+        assert_eq!(parse_size("1.5MiB"), Some(3 << 19));
+        assert_eq!(parse_size("2gb"), Some(2 << 30));
+
+        for invalid in ["", "-1M", "2T", "inf", "18446744073709551616", "100000000000G"] {
+            assert_eq!(parse_size(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+/* Checkpoints saved by the long-running solvers, so that an interrupted search can be resumed.
+ *
+ * A checkpoint is a set of `key=value` lines chosen by the solver. The runner adds the hash of
+ * the input to it so that a checkpoint is never resumed with another input. The checkpoint of a part
+ * is named after the crate of its solver, so that the binary of a day and `aoc run` share it.
+ */
+use crate::input;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+const INPUT_HASH_KEY: &str = "input_hash";
+
+/// Directory of the checkpoints, next to the one of the inputs.
+pub const CHECKPOINT_DIR: &str = "../checkpoints";
+
+/// Minimum time between two writes of the checkpoint file.
+pub const SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Checkpoint file of the solver of the crate `krate`, e.g. `../checkpoints/aoc_06_part2.txt`.
+pub fn checkpoint_file(krate: &str) -> PathBuf {
+    PathBuf::from(format!("{CHECKPOINT_DIR}/{krate}.txt"))
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Checkpoint {
+    values: BTreeMap<String, String>,
+}
+
+impl Checkpoint {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with<T: Display>(mut self, key: &str, value: T) -> Self {
+        self.set(key, value);
+        self
+    }
+
+    pub fn set<T: Display>(&mut self, key: &str, value: T) {
+        self.values.insert(key.to_string(), value.to_string());
+    }
+
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.values.get(key).and_then(|v| v.parse().ok())
+    }
+
+    /// Same as `get`, with an error message suitable for a solver when the value is missing.
+    pub fn require<T: FromStr>(&self, key: &str) -> Result<T, String> {
+        self.get(key).ok_or(format!("Checkpoint has no valid value for '{key}'"))
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        for (key, value) in &self.values {
+            text.push_str(&format!("{key}={value}\n"));
+        }
+
+        text
+    }
+
+    pub fn from_text(text: &str) -> Result<Self, String> {
+        let mut checkpoint = Checkpoint::new();
+
+        for (idx, line) in text.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match line.split_once('=') {
+                Some((key, value)) => checkpoint.set(key.trim(), value.trim()),
+                None => return Err(format!("line {}: expected 'key=value'", idx + 1)),
+            }
+        }
+
+        Ok(checkpoint)
+    }
+}
+
+/// Where and when the checkpoints of a run are saved.
+pub struct CheckpointStore {
+    path: PathBuf,
+    input_hash: String,
+    resumed: Option<Checkpoint>,
+    last_save: Mutex<Option<Instant>>,
+}
+
+impl CheckpointStore {
+    /// Prepares the checkpoints of a run over `input`, loading the existing one if `resume` is set.
+    pub fn open(path: &Path, input: &str, resume: bool) -> Result<Self, String> {
+        let input_hash = input::hash(input);
+        let mut resumed = None;
+
+        if resume && path.is_file() {
+            let text = fs::read_to_string(path).map_err(|e| format!("Could not read checkpoint '{}': {e}", path.display()))?;
+            let checkpoint = Checkpoint::from_text(&text).map_err(|e| format!("Invalid checkpoint '{}': {e}", path.display()))?;
+
+            if checkpoint.get::<String>(INPUT_HASH_KEY).as_ref() != Some(&input_hash) {
+                return Err(format!("Checkpoint '{}' was saved for another input", path.display()));
+            }
+            resumed = Some(checkpoint);
+        }
+
+        Ok(Self { path: path.to_path_buf(), input_hash, resumed, last_save: Mutex::new(None) })
+    }
+
+    /// Checkpoint to resume from, if any
+    pub fn resumed(&self) -> Option<&Checkpoint> {
+        self.resumed.as_ref()
+    }
+
+    /// Writes the checkpoint built by `build`, unless the last one was written less than
+    /// `SAVE_INTERVAL` ago and `force` is not set.
+    pub fn save<F>(&self, force: bool, build: F) -> Result<(), String>
+    where
+        F: FnOnce() -> Checkpoint,
+    {
+        let mut last_save = self.last_save.lock().unwrap();
+        if !force && last_save.is_some_and(|t| t.elapsed() < SAVE_INTERVAL) {
+            return Ok(());
+        }
+
+        let checkpoint = build().with(INPUT_HASH_KEY, &self.input_hash);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Could not create '{}': {e}", dir.display()))?;
+        }
+
+        /* Write then rename, so that an interruption never leaves a partial checkpoint */
+        let tmp_path = self.path.with_extension("tmp");
+        fs::write(&tmp_path, format!("# Checkpoint of an interrupted run\n{}", checkpoint.to_text()))
+            .and_then(|_| fs::rename(&tmp_path, &self.path))
+            .map_err(|e| format!("Could not write checkpoint '{}': {e}", self.path.display()))?;
+
+        *last_save = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Removes the checkpoint once the run is complete.
+    pub fn remove(&self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_after_the_crate() {
+        assert_eq!(checkpoint_file("aoc_06_part2"), Path::new("../checkpoints/aoc_06_part2.txt"));
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let checkpoint = Checkpoint::new().with("step", 42).with("position", "3,4");
+        assert_eq!(Checkpoint::from_text(&checkpoint.to_text()), Ok(checkpoint.clone()));
+        assert_eq!(Checkpoint::from_text("# comment\n\n step = 42 \nposition=3,4\n"), Ok(checkpoint));
+        assert_eq!(Checkpoint::from_text("step").unwrap_err(), "line 1: expected 'key=value'");
+    }
+}
@@ -0,0 +1,120 @@
+/* Two-dimensional grid of cells, stored row by row.
+ *
+ * Most of the puzzles give a map of characters, which is parsed into a `Grid<char>`.
+ */
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self { width, height, cells: vec![fill; width * height] }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, String> {
+        let width = rows.first().map_or(0, |r| r.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!("line {}: expected {width} cells, found {}", y + 1, row.len()));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Self { width, height, cells })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i64, y: i64) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Changes the value of a cell, ignored when the cell is outside the grid.
+    pub fn set(&mut self, x: usize, y: usize, value: T) {
+        if let Some(cell) = self.get_mut(x, y) {
+            *cell = value;
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        /* chunks() does not accept a size of 0 */
+        self.cells.chunks(self.width.max(1)).take(self.height)
+    }
+
+    /// Cells with their position, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.cells.iter().enumerate().map(|(idx, cell)| (idx % self.width, idx / self.width, cell))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map of characters, every non-blank line being a row.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        Self::from_rows(input.lines()
+                             .filter(|l| !l.trim().is_empty())
+                             .map(|l| l.chars().collect())
+                             .collect())
+    }
+
+    /// Builds a grid from lines of text, padding the short lines with spaces.
+    pub fn from_lines<S: AsRef<str>>(lines: &[S]) -> Self {
+        let width = lines.iter().map(|l| l.as_ref().chars().count()).max().unwrap_or(0);
+        let mut grid = Grid::new(width, lines.len(), ' ');
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.as_ref().chars().enumerate() {
+                grid.set(x, y, c);
+            }
+        }
+
+        grid
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
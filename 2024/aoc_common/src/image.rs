@@ -0,0 +1,157 @@
+/* Export of the grids and of the animations as PNG images or animated GIF.
+ *
+ * Every cell of a grid becomes a square of `scale` pixels, coloured with the palette.
+ */
+use crate::animation::{colour_of, Colour, Frame};
+use crate::grid::Grid;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// Size in pixels of the square drawn for each cell.
+pub const DEFAULT_SCALE: usize = 4;
+
+/// Colour of the characters missing from the palette of a frame.
+const DEFAULT_COLOUR: Colour = Colour::rgb(220, 220, 220);
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<Colour>,
+}
+
+impl Image {
+    fn to_rgb(&self) -> Vec<u8> {
+        self.pixels.iter().flat_map(|c| [c.r, c.g, c.b]).collect()
+    }
+}
+
+/// Draws the grid, `colour` giving the colour of each cell.
+pub fn rasterize<T, F>(grid: &Grid<T>, scale: usize, colour: F) -> Image
+where
+    F: Fn(&T) -> Colour,
+{
+    let width = grid.width() * scale;
+    let height = grid.height() * scale;
+    let mut pixels = vec![DEFAULT_COLOUR; width * height];
+
+    for (x, y, cell) in grid.cells() {
+        let colour = colour(cell);
+        for py in y * scale..(y + 1) * scale {
+            pixels[py * width + x * scale..py * width + (x + 1) * scale].fill(colour);
+        }
+    }
+
+    Image { width, height, pixels }
+}
+
+pub fn frame_image(frame: &Frame, scale: usize) -> Image {
+    rasterize(&frame.grid, scale, |c| colour_of(frame.palette, *c).unwrap_or(DEFAULT_COLOUR))
+}
+
+pub fn write_png(path: &Path, image: &Image) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    encode_png(BufWriter::new(file), image)
+}
+
+pub fn encode_png<W: Write>(out: W, image: &Image) -> Result<(), String> {
+    let mut encoder = png::Encoder::new(out, image.width as u32, image.height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+    writer.write_image_data(&image.to_rgb()).map_err(|e| e.to_string())
+}
+
+pub fn write_gif(path: &Path, images: &[Image], fps: f64) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    encode_gif(BufWriter::new(file), images, fps)
+}
+
+/// Encodes the images as an animated GIF played in loop, the images must have the same size.
+pub fn encode_gif<W: Write>(out: W, images: &[Image], fps: f64) -> Result<(), String> {
+    let Some(first) = images.first() else {
+        return Err("No image to write".to_string());
+    };
+    if first.width > u16::MAX as usize || first.height > u16::MAX as usize {
+        return Err(format!("Image of {}x{} pixels is too large for a GIF", first.width, first.height));
+    }
+    if images.iter().any(|i| i.width != first.width || i.height != first.height) {
+        return Err("The images of an animation must have the same size".to_string());
+    }
+
+    /* The grids use few colours, which fit in a global palette. Else each frame is quantized */
+    let mut indexes: HashMap<Colour, u8> = HashMap::new();
+    for colour in images.iter().flat_map(|i| &i.pixels) {
+        if !indexes.contains_key(colour) && indexes.len() <= 256 {
+            indexes.insert(*colour, indexes.len() as u8);
+        }
+    }
+    let global_palette = indexes.len() <= 256;
+    let mut palette = vec![0u8; indexes.len().min(256) * 3];
+    if global_palette {
+        for (colour, idx) in &indexes {
+            palette[*idx as usize * 3..*idx as usize * 3 + 3].copy_from_slice(&[colour.r, colour.g, colour.b]);
+        }
+    }
+
+    let (width, height) = (first.width as u16, first.height as u16);
+    let mut encoder = gif::Encoder::new(out, width, height, if global_palette { &palette } else { &[] })
+                          .map_err(|e| e.to_string())?;
+    encoder.set_repeat(gif::Repeat::Infinite).map_err(|e| e.to_string())?;
+
+    /* The delay is given in hundredths of second */
+    let delay = (100.0 / fps).round().clamp(1.0, u16::MAX as f64) as u16;
+    for image in images {
+        let mut frame = if global_palette {
+            gif::Frame {
+                width,
+                height,
+                buffer: Cow::Owned(image.pixels.iter().map(|c| indexes[c]).collect()),
+                ..gif::Frame::default()
+            }
+        }
+        else {
+            gif::Frame::from_rgb_speed(width, height, &image.to_rgb(), 10)
+        };
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Renders the frames according to the extension of `path`: an animated GIF for `.gif`, and PNG
+/// images for `.png`, numbered after the name of the file when there are several frames.
+/// Returns the files written.
+pub fn render_frames(frames: &[Frame], path: &Path, fps: f64, scale: usize) -> Result<Vec<PathBuf>, String> {
+    let extension = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+    let images: Vec<Image> = frames.iter().map(|f| frame_image(f, scale)).collect();
+    let error = |e: String| format!("Could not write '{}': {e}", path.display());
+
+    match extension.as_deref() {
+        Some("gif") => {
+            write_gif(path, &images, fps).map_err(error)?;
+            Ok(vec![path.to_path_buf()])
+        },
+        Some("png") if images.len() == 1 => {
+            write_png(path, &images[0]).map_err(error)?;
+            Ok(vec![path.to_path_buf()])
+        },
+        Some("png") => {
+            let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+            let mut written = Vec::new();
+
+            for (idx, image) in images.iter().enumerate() {
+                let numbered = path.with_file_name(format!("{stem}_{:04}.png", idx + 1));
+                write_png(&numbered, image).map_err(|e| format!("Could not write '{}': {e}", numbered.display()))?;
+                written.push(numbered);
+            }
+            Ok(written)
+        },
+        _ => Err(format!("Unknown image format for '{}', expected .gif or .png", path.display())),
+    }
+}
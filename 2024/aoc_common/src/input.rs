@@ -0,0 +1,106 @@
+/* Source of the input of a puzzle: a file, the standard input, or the input compiled into the
+ * binary by the `embed-input` feature of a day.
+ *
+ * `-` stands for the standard input. It is also read when no file is given and the standard input is
+ * a pipe or a file, so that the binaries can be used in shell pipelines, e.g.
+ * `cat input.txt | aoc run --day 9`. This is decided from its type alone: it is never read before it
+ * is selected, as a pipe kept open by a parent process, e.g. under ssh or in CI, would block. Such a
+ * parent can give `</dev/null`, or the input with `-`.
+ */
+use std::fmt;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Argument standing for the standard input.
+pub const STDIN_ARG: &str = "-";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+    Embedded(&'static str),
+}
+
+impl InputSource {
+    pub fn from_arg(arg: &str) -> Self {
+        if arg == STDIN_ARG { Self::Stdin } else { Self::File(PathBuf::from(arg)) }
+    }
+
+    /// Source given by `arg`. Without it, the standard input when something is piped into it, else
+    /// the `default` file.
+    pub fn select(arg: Option<&str>, default: impl Into<PathBuf>) -> Self {
+        Self::select_or(arg, Self::File(default.into()))
+    }
+
+    /// Same as `select`, with any source as the default.
+    pub fn select_or(arg: Option<&str>, default: Self) -> Self {
+        match arg {
+            Some(arg) => Self::from_arg(arg),
+            None if stdin_is_piped() => Self::Stdin,
+            None => default,
+        }
+    }
+
+    pub fn read(&self) -> Result<String, String> {
+        match self {
+            Self::Stdin => read_stdin(),
+            Self::File(path) => read_to_string(path).map_err(|e| format!("Could not read '{}': {e}", path.display())),
+            Self::Embedded(input) => Ok(input.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "standard input"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Embedded(_) => write!(f, "embedded input"),
+        }
+    }
+}
+
+/// Reads the whole standard input. It is read once, so that several parts can be solved with it.
+pub fn read_stdin() -> Result<String, String> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+    STDIN.get_or_init(|| {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map_err(|e| format!("Could not read the standard input: {e}"))?;
+        Ok(input)
+    }).clone()
+}
+
+/// Stable hash of a text such as an input, as the hash of the standard library may change between
+/// versions. It is the 64-bit FNV-1a, written in hexadecimal.
+pub fn hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    format!("{hash:016x}")
+}
+
+/// Whether the standard input is a pipe or a file which is not empty, from its `fstat`. A terminal,
+/// a socket or a device such as `/dev/null` is not taken for an input.
+#[cfg(unix)]
+pub fn stdin_is_piped() -> bool {
+    use std::fs::File;
+    use std::os::fd::AsFd;
+    use std::os::unix::fs::FileTypeExt;
+
+    /* The metadata of a duplicate of the descriptor, which closes it when dropped */
+    let metadata = io::stdin().as_fd().try_clone_to_owned().and_then(|fd| File::from(fd).metadata());
+    metadata.is_ok_and(|m| m.file_type().is_fifo() || (m.file_type().is_file() && m.len() > 0))
+}
+
+/// Without `fstat`, the standard input is only read when given with `-`.
+#[cfg(not(unix))]
+pub fn stdin_is_piped() -> bool {
+    false
+}
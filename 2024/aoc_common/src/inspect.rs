@@ -0,0 +1,81 @@
+/* Statistics of an input, to get quick facts about it before writing a solver.
+ *
+ * The general statistics hold for any input: its dimensions, its characters and its numbers. A day
+ * can add its own with an `Inspector`, such as the number of antenna frequencies of day 8.
+ */
+use crate::parse;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Statistics of a day on its input.
+pub type Inspector = fn(&str) -> Result<Vec<Stat>, String>;
+
+/// Most characters listed by the histogram, the rarest ones being summed up.
+const MAX_HISTOGRAM_CHARS: usize = 16;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stat {
+    pub name: String,
+    pub value: String,
+}
+
+impl Stat {
+    pub fn new(name: impl Into<String>, value: impl Display) -> Self {
+        Self { name: name.into(), value: value.to_string() }
+    }
+}
+
+/// Number of occurrences of each value, in increasing order, e.g. `2: 5, 3: 1`.
+pub fn distribution<T: Ord + Display>(values: impl IntoIterator<Item = T>) -> String {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+
+    counts.iter().map(|(value, count)| format!("{value}: {count}")).collect::<Vec<_>>().join(", ")
+}
+
+/// Dimensions, characters and numbers of any input.
+pub fn general(input: &str) -> Vec<Stat> {
+    let lines: Vec<&str> = input.lines().collect();
+    let widths: Vec<usize> = lines.iter().filter(|l| !l.is_empty()).map(|l| l.chars().count()).collect();
+    let mut stats = vec![Stat::new("Bytes", input.len()), Stat::new("Lines", lines.len())];
+
+    /* Blank lines separate the sections of the inputs made of several parts */
+    let blank_lines = lines.iter().filter(|l| l.trim().is_empty()).count();
+    if blank_lines > 0 {
+        stats.push(Stat::new("Blank lines", blank_lines));
+    }
+
+    /* Lines of the same width without spaces, the numbers in columns being no grid */
+    let grid = widths.len() > 1 && widths.iter().all(|w| *w == widths[0]) && !input.contains([' ', '\t']);
+    match (widths.iter().min(), widths.iter().max()) {
+        (Some(min), Some(_)) if grid => stats.push(Stat::new("Grid", format!("{min} x {}", widths.len()))),
+        (Some(min), Some(max)) if min == max => stats.push(Stat::new("Line width", min)),
+        (Some(min), Some(max)) => stats.push(Stat::new("Line width", format!("{min} to {max}"))),
+        _ => {},
+    }
+
+    let mut chars: BTreeMap<char, usize> = BTreeMap::new();
+    for c in input.chars().filter(|c| *c != '\n' && *c != '\r') {
+        *chars.entry(c).or_insert(0) += 1;
+    }
+    let mut histogram: Vec<(char, usize)> = chars.into_iter().collect();
+    histogram.sort_by_key(|(c, count)| (std::cmp::Reverse(*count), *c));
+    let others: usize = histogram.iter().skip(MAX_HISTOGRAM_CHARS).map(|(_, count)| count).sum();
+    let mut listed: Vec<String> = histogram.iter().take(MAX_HISTOGRAM_CHARS).map(|(c, count)| format!("{c:?}: {count}")).collect();
+    if others > 0 {
+        listed.push(format!("{} others: {others}", histogram.len() - MAX_HISTOGRAM_CHARS));
+    }
+    stats.push(Stat::new("Characters", format!("{} distinct, {}", histogram.len(), listed.join(", "))));
+
+    /* The digits of a map, such as heights, are cells and not numbers */
+    let digit_map = grid || lines.iter().all(|l| l.chars().all(|c| c.is_ascii_digit()));
+    let numbers: Vec<i64> = lines.iter().flat_map(|l| parse::integers(l)).collect();
+    if let (false, Some(min), Some(max)) = (digit_map, numbers.iter().min(), numbers.iter().max()) {
+        stats.push(Stat::new("Numbers", format!("{}, from {min} to {max}", numbers.len())));
+        stats.push(Stat::new("Numbers by line", distribution(lines.iter().map(|l| parse::integers::<i64>(l).len()))));
+    }
+
+    stats
+}
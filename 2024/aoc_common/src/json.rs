@@ -0,0 +1,404 @@
+/* Minimal JSON reader and writer, enough for the files exchanged with other tools such as the
+ * leaderboards of Advent of Code.
+ *
+ * The numbers are kept as `f64`, as in JavaScript. The keys of the objects are sorted, which makes
+ * the output stable.
+ */
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Json>),
+    Object(BTreeMap<String, Json>),
+}
+
+impl Json {
+    pub fn parse(text: &str) -> Result<Json, String> {
+        let mut parser = Parser { text, pos: 0 };
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.pos == text.len() {
+            true => Ok(value),
+            false => Err(parser.error("end of the text")),
+        }
+    }
+
+    /// Value of `key`, when this is an object which has it.
+    pub fn get(&self, key: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields.get(key),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Json::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    /// Number or string holding an integer, as the ids are sometimes written in both ways.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Json::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Some(*n as u64),
+            Json::String(s) => s.parse().ok(),
+            _ => None,
+        }
+    }
+
+    pub fn as_object(&self) -> Option<&BTreeMap<String, Json>> {
+        match self {
+            Json::Object(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Object made of the given fields.
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Json {
+        Json::Object(fields.into_iter().map(|(k, v)| (k.into(), v)).collect())
+    }
+
+    /// Indented text, one value per line.
+    pub fn pretty(&self) -> String {
+        let mut text = String::new();
+        self.write_pretty(&mut text, 0);
+        text
+    }
+
+    fn write_pretty(&self, text: &mut String, depth: usize) {
+        let indent = |depth: usize| "  ".repeat(depth);
+        match self {
+            Json::Array(items) if !items.is_empty() => {
+                text.push_str("[\n");
+                for (idx, item) in items.iter().enumerate() {
+                    text.push_str(&indent(depth + 1));
+                    item.write_pretty(text, depth + 1);
+                    text.push_str(if idx + 1 < items.len() { ",\n" } else { "\n" });
+                }
+                text.push_str(&indent(depth));
+                text.push(']');
+            },
+            Json::Object(fields) if !fields.is_empty() => {
+                text.push_str("{\n");
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    text.push_str(&format!("{}{}: ", indent(depth + 1), Json::String(key.clone())));
+                    value.write_pretty(text, depth + 1);
+                    text.push_str(if idx + 1 < fields.len() { ",\n" } else { "\n" });
+                }
+                text.push_str(&indent(depth));
+                text.push('}');
+            },
+            value => text.push_str(&value.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(b) => write!(f, "{b}"),
+            Json::Number(n) if n.is_finite() => write!(f, "{n}"),
+            Json::Number(_) => write!(f, "null"),
+            Json::String(s) => {
+                write!(f, "\"")?;
+                for c in s.chars() {
+                    match c {
+                        '"' => write!(f, "\\\"")?,
+                        '\\' => write!(f, "\\\\")?,
+                        '\n' => write!(f, "\\n")?,
+                        '\r' => write!(f, "\\r")?,
+                        '\t' => write!(f, "\\t")?,
+                        c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+                write!(f, "\"")
+            },
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    write!(f, "{}{item}", if idx > 0 { "," } else { "" })?;
+                }
+                write!(f, "]")
+            },
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (idx, (key, value)) in fields.iter().enumerate() {
+                    write!(f, "{}{}:{value}", if idx > 0 { "," } else { "" }, Json::String(key.clone()))?;
+                }
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(s: &str) -> Self {
+        Json::String(s.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(s: String) -> Self {
+        Json::String(s)
+    }
+}
+
+impl From<u64> for Json {
+    fn from(n: u64) -> Self {
+        Json::Number(n as f64)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Json::Null)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(items: Vec<T>) -> Self {
+        Json::Array(items.into_iter().map(Into::into).collect())
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, expected: &str) -> String {
+        let line = self.text[..self.pos].matches('\n').count() + 1;
+        let line_start = self.text[..self.pos].rfind('\n').map_or(0, |n| n + 1);
+        let column = self.text[line_start..self.pos].chars().count() + 1;
+        format!("Invalid JSON at line {line}, column {column}: expected {expected}")
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), String> {
+        self.skip_whitespace();
+        match self.text[self.pos..].starts_with(token) {
+            true => {
+                self.pos += token.len();
+                Ok(())
+            },
+            false => Err(self.error(&format!("'{token}'"))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, String> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.object(),
+            Some('[') => self.array(),
+            Some('"') => Ok(Json::String(self.string()?)),
+            Some('t') => self.expect("true").map(|_| Json::Bool(true)),
+            Some('f') => self.expect("false").map(|_| Json::Bool(false)),
+            Some('n') => self.expect("null").map(|_| Json::Null),
+            Some(c) if c == '-' || c.is_ascii_digit() => self.number(),
+            _ => Err(self.error("a value")),
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, String> {
+        let mut fields = BTreeMap::new();
+        self.expect("{")?;
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.pos += 1;
+            return Ok(Json::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("a key"));
+            }
+            let key = self.string()?;
+            self.expect(":")?;
+            fields.insert(key, self.value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some('}') => {
+                    self.pos += 1;
+                    return Ok(Json::Object(fields));
+                },
+                _ => return Err(self.error("',' or '}'")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Json, String> {
+        let mut items = Vec::new();
+        self.expect("[")?;
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(Json::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.pos += 1,
+                Some(']') => {
+                    self.pos += 1;
+                    return Ok(Json::Array(items));
+                },
+                _ => return Err(self.error("',' or ']'")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.pos += 1;
+        let mut result = String::new();
+
+        loop {
+            let c = self.peek().ok_or_else(|| self.error("'\"'"))?;
+            self.pos += c.len_utf8();
+            match c {
+                '"' => return Ok(result),
+                '\\' => {
+                    let escaped = self.peek().ok_or_else(|| self.error("an escaped character"))?;
+                    self.pos += escaped.len_utf8();
+                    match escaped {
+                        '"' | '\\' | '/' => result.push(escaped),
+                        'b' => result.push('\u{8}'),
+                        'f' => result.push('\u{c}'),
+                        'n' => result.push('\n'),
+                        'r' => result.push('\r'),
+                        't' => result.push('\t'),
+                        'u' => result.push(self.unicode_escape()?),
+                        _ => return Err(self.error("an escaped character")),
+                    }
+                },
+                c => result.push(c),
+            }
+        }
+    }
+
+    /// Character of a `\uXXXX` escape, the characters outside of the BMP being written as a
+    /// surrogate pair.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let mut hex = || -> Result<u32, String> {
+            let digits = self.text.get(self.pos..self.pos + 4).ok_or_else(|| self.error("4 hexadecimal digits"))?;
+            let code = u32::from_str_radix(digits, 16).map_err(|_| self.error("4 hexadecimal digits"))?;
+            self.pos += 4;
+            Ok(code)
+        };
+
+        let high = hex()?;
+        let code = match high {
+            0xd800..=0xdbff => {
+                self.expect("\\u")?;
+                let low = self.unicode_escape_low()?;
+                0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00)
+            },
+            code => code,
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("a valid unicode character"))
+    }
+
+    fn unicode_escape_low(&mut self) -> Result<u32, String> {
+        let digits = self.text.get(self.pos..self.pos + 4).ok_or_else(|| self.error("4 hexadecimal digits"))?;
+        match u32::from_str_radix(digits, 16) {
+            Ok(code @ 0xdc00..=0xdfff) => {
+                self.pos += 4;
+                Ok(code)
+            },
+            _ => Err(self.error("a low surrogate")),
+        }
+    }
+
+    fn number(&mut self) -> Result<Json, String> {
+        let rest = &self.text[self.pos..];
+        let len = rest.find(|c: char| !(c.is_ascii_digit() || "+-.eE".contains(c))).unwrap_or(rest.len());
+        let number = rest[..len].parse().map_err(|_| self.error("a number"))?;
+        self.pos += len;
+        Ok(Json::Number(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_nested_values() {
+        let json = Json::parse(r#" {"a": [1, -2.5e1, true, null], "b": {"c": "d\n"}, "e": []} "#).unwrap();
+
+        assert_eq!(json.get("a"), Some(&Json::Array(vec![Json::Number(1.0), Json::Number(-25.0), Json::Bool(true), Json::Null])));
+        assert_eq!(json.get("b").and_then(|b| b.get("c")).and_then(Json::as_str), Some("d\n"));
+        assert_eq!(json.get("e"), Some(&Json::Array(Vec::new())));
+        assert_eq!(json.get("f"), None);
+    }
+
+    #[test]
+    fn reads_ids_as_numbers_or_strings() {
+        assert_eq!(Json::parse("42").unwrap().as_u64(), Some(42));
+        assert_eq!(Json::parse(r#""42""#).unwrap().as_u64(), Some(42));
+        assert_eq!(Json::parse("4.5").unwrap().as_u64(), None);
+        assert_eq!(Json::parse("-1").unwrap().as_u64(), None);
+    }
+
+    #[test]
+    fn decodes_unicode_escapes() {
+        assert_eq!(Json::parse(r#""\u00e9""#).unwrap(), Json::from("é"));
+        assert_eq!(Json::parse(r#""\ud83c\udf84""#).unwrap(), Json::from("🎄"));
+        assert!(Json::parse(r#""\ud83c""#).is_err());
+        assert!(Json::parse(r#""\ud83c\u0041""#).is_err());
+        assert!(Json::parse(r#""\u12""#).is_err());
+    }
+
+    #[test]
+    fn locates_the_errors() {
+        assert_eq!(Json::parse("{\n  \"a\": tru\n}").unwrap_err(), "Invalid JSON at line 2, column 8: expected 'true'");
+        assert_eq!(Json::parse("[1 2]").unwrap_err(), "Invalid JSON at line 1, column 4: expected ',' or ']'");
+        assert_eq!(Json::parse("{} x").unwrap_err(), "Invalid JSON at line 1, column 4: expected end of the text");
+        assert_eq!(Json::parse(r#"{"a" 1}"#).unwrap_err(), "Invalid JSON at line 1, column 6: expected ':'");
+    }
+
+    #[test]
+    fn rejects_an_escaped_multibyte_character_without_panicking() {
+        assert_eq!(Json::parse(r#""\é""#).unwrap_err(), "Invalid JSON at line 1, column 4: expected an escaped character");
+        assert!(Json::parse("\"\\").is_err());
+    }
+
+    #[test]
+    fn writes_what_it_reads() {
+        let text = r#"{"a":[1,"x\"y\\z\u0001"],"b":null}"#;
+        let json = Json::parse(text).unwrap();
+
+        assert_eq!(json.to_string(), text);
+        assert_eq!(Json::parse(&json.pretty()).unwrap(), json);
+    }
+}
@@ -0,0 +1,21 @@
+/* ADVENT OF CODE
+ * Code shared by the solutions of each day.
+ */
+pub mod alloc;
+pub mod animation;
+pub mod answer;
+pub mod args;
+pub mod checkpoint;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod inspect;
+pub mod json;
+pub mod parse;
+pub mod profile;
+pub mod runner;
+pub mod sandbox;
+pub mod snapshot;
+
+pub use answer::Answer;
+pub use runner::Context;
@@ -0,0 +1,512 @@
+/* Helpers to parse the puzzle inputs.
+ *
+ * Line and column numbers start at 1 so they can be reported as is to the user.
+ *
+ * The solvers validate the shape of their input up front with `grid`, `sections_exact` and
+ * `Section::find_unique`, and report the errors with `ParseError::annotate`, which points at the
+ * offending character:
+ *
+ *   line 3, column 5: unexpected character 'x', expected '.', '#' or '^'
+ *     |
+ *   3 | ..#.x.....
+ *     |     ^
+ */
+use std::fmt;
+use std::ops::RangeBounds;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: &str) -> Self {
+        Self { line, column, message: message.to_string() }
+    }
+
+    /// Describes the error followed by the line of `input` where it occurred, with a caret under
+    /// its column.
+    pub fn annotate(&self, input: &str) -> String {
+        let Some(text) = self.line.checked_sub(1).and_then(|idx| input.lines().nth(idx)) else {
+            return self.to_string();
+        };
+
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+        format!("{self}\n{margin} |\n{number} | {text}\n{margin} | {}^", " ".repeat(self.column.saturating_sub(1)))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Block of consecutive non-blank lines of the input.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Section<'a> {
+    /// Line number of the first line of the section in the input
+    pub first_line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Section<'a> {
+    /// Parses each line of the section with `parser`.
+    pub fn parse_lines<T, F>(&self, mut parser: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+    {
+        let mut result = Vec::with_capacity(self.lines.len());
+
+        for (idx, line) in self.lines.iter().enumerate() {
+            let mut cursor = Cursor::new(line, self.first_line + idx);
+            let value = parser(&mut cursor)?;
+            cursor.finish()?;
+            result.push(value);
+        }
+
+        Ok(result)
+    }
+
+    /// Finds the only occurrence of `target` in the section, `name` describing it in the errors.
+    /// Returns its position from the start of the section, starting at 0.
+    pub fn find_unique(&self, target: char, name: &str) -> Result<(usize, usize), ParseError> {
+        let mut found = None;
+
+        for (y, line) in self.lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if c != target {
+                    continue;
+                }
+                if let Some((first_x, first_y)) = found {
+                    return Err(ParseError::new(self.first_line + y, x + 1,
+                                               &format!("second {name} '{target}', the first one is at line {}, column {}",
+                                                        self.first_line + first_y, first_x + 1)));
+                }
+                found = Some((x, y));
+            }
+        }
+
+        found.ok_or(ParseError::new(self.first_line, 1, &format!("no {name} '{target}' found")))
+    }
+}
+
+/// Splits the input into sections separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Section<'_>> {
+    let mut result = Vec::new();
+    let mut current: Option<Section> = None;
+
+    for (idx, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            if let Some(section) = current.take() {
+                result.push(section);
+            }
+        }
+        else {
+            current
+                .get_or_insert_with(|| Section { first_line: idx + 1, lines: Vec::new() })
+                .lines
+                .push(line);
+        }
+    }
+
+    if let Some(section) = current {
+        result.push(section);
+    }
+
+    result
+}
+
+/// Splits the input into exactly `names.len()` sections, `names` describing them in the errors.
+pub fn sections_exact<'a>(input: &'a str, names: &[&str]) -> Result<Vec<Section<'a>>, ParseError> {
+    let sections = sections(input);
+    let expected = match names {
+        [.., before_last, last] => format!("expected {}{before_last} and {last} separated by a blank line",
+                                           names[..names.len() - 2].iter().map(|n| format!("{n}, ")).collect::<String>()),
+        _ => format!("expected only {}", names.join("")),
+    };
+
+    match sections.get(names.len()) {
+        Some(extra) => Err(ParseError::new(extra.first_line, 1, &format!("unexpected section, {expected}"))),
+        None if sections.len() < names.len() => {
+            let line = sections.last().map(|s| s.first_line + s.lines.len()).unwrap_or(1);
+            Err(ParseError::new(line, 1, &format!("missing {}, {expected}", names[sections.len()])))
+        },
+        None => Ok(sections),
+    }
+}
+
+/// Verifies that the input is a single rectangular map, made of the characters accepted by
+/// `allowed`, and returns its rows. `expected` describes those characters in the errors.
+pub fn grid<'a, F>(input: &'a str, expected: &str, allowed: F) -> Result<Section<'a>, ParseError>
+where
+    F: Fn(char) -> bool,
+{
+    let mut sections = sections(input).into_iter();
+    let Some(map) = sections.next() else {
+        return Err(ParseError::new(1, 1, "empty map"));
+    };
+    if let Some(extra) = sections.next() {
+        return Err(ParseError::new(extra.first_line - 1, 1, "unexpected blank line inside the map"));
+    }
+
+    let width = map.lines[0].chars().count();
+    for (idx, line) in map.lines.iter().enumerate() {
+        let line_number = map.first_line + idx;
+
+        if let Some((column, c)) = line.chars().enumerate().find(|(_, c)| !allowed(*c)) {
+            return Err(ParseError::new(line_number, column + 1, &format!("unexpected character '{c}', expected {expected}")));
+        }
+
+        let line_width = line.chars().count();
+        if line_width != width {
+            return Err(ParseError::new(line_number, line_width.min(width) + 1,
+                                       &format!("row of width {line_width}, expected {width} like the first row")));
+        }
+    }
+
+    Ok(map)
+}
+
+/// Verifies that the input is a single line, made of the characters accepted by `allowed`.
+pub fn single_line<'a, F>(input: &'a str, expected: &str, allowed: F) -> Result<Section<'a>, ParseError>
+where
+    F: Fn(char) -> bool,
+{
+    if let Some((idx, _)) = input.lines().enumerate().filter(|(_, l)| !l.trim().is_empty()).nth(1) {
+        return Err(ParseError::new(idx + 1, 1, "unexpected second line, expected a single line"));
+    }
+
+    grid(input, expected, allowed)
+}
+
+/// Parses each non-blank line of the input with `parser`.
+pub fn parse_lines<'a, T, F>(input: &'a str, mut parser: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&mut Cursor<'a>) -> Result<T, ParseError>,
+{
+    let mut result = Vec::new();
+
+    for section in sections(input) {
+        result.extend(section.parse_lines(&mut parser)?);
+    }
+
+    Ok(result)
+}
+
+/// Extracts all the signed integers of a line, ignoring everything else.
+///
+/// A `-` is only taken as a sign when it is directly followed by a digit and not preceded by
+/// one, so `3-4` gives `[3, 4]`. Numbers which do not fit in `T` are skipped.
+pub fn integers<T: FromStr>(line: &str) -> Vec<T> {
+    let bytes = line.as_bytes();
+    let mut result = Vec::new();
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        let negative = bytes[idx] == b'-' &&
+                       bytes.get(idx + 1).is_some_and(u8::is_ascii_digit) &&
+                       (idx == 0 || !bytes[idx - 1].is_ascii_digit());
+
+        if negative || bytes[idx].is_ascii_digit() {
+            let start = idx;
+            idx += 1;
+            while idx < bytes.len() && bytes[idx].is_ascii_digit() {
+                idx += 1;
+            }
+
+            if let Ok(value) = line[start..idx].parse() {
+                result.push(value);
+            }
+        }
+        else {
+            idx += 1;
+        }
+    }
+
+    result
+}
+
+/// Position in a line of the input, used to build small parsers by chaining its methods.
+///
+/// Apart from `literal_exact`, every method skips the whitespace in front of what it parses.
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    text: &'a str,
+    line: usize,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(text: &'a str, line: usize) -> Self {
+        Self { text, line, pos: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Column of the next character to be parsed
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// Not yet parsed part of the line
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn is_at_end(&self) -> bool {
+        self.rest().trim_start().is_empty()
+    }
+
+    /// Builds an error located at the current position of the cursor.
+    pub fn error(&self, message: &str) -> ParseError {
+        ParseError::new(self.line, self.column(), message)
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Parses an optionally signed integer.
+    pub fn integer<T: FromStr>(&mut self) -> Result<T, ParseError> {
+        self.skip_whitespace();
+
+        let bytes = self.rest().as_bytes();
+        let mut len = 0;
+        if bytes.first().is_some_and(|&b| b == b'-' || b == b'+') {
+            len += 1;
+        }
+        let digits_start = len;
+        while bytes.get(len).is_some_and(u8::is_ascii_digit) {
+            len += 1;
+        }
+
+        if len == digits_start {
+            return Err(self.error("expected an integer"));
+        }
+
+        match self.rest()[..len].parse() {
+            Ok(value) => {
+                self.pos += len;
+                Ok(value)
+            },
+            Err(_) => Err(self.error(&format!("integer '{}' is out of range", &self.rest()[..len]))),
+        }
+    }
+
+    /// Parses an optionally signed integer, which must be within `range`.
+    pub fn integer_in<T, R>(&mut self, range: R) -> Result<T, ParseError>
+    where
+        T: FromStr + PartialOrd + fmt::Display,
+        R: RangeBounds<T> + fmt::Debug,
+    {
+        self.skip_whitespace();
+        let start = self.clone();
+
+        let value = self.integer()?;
+        if !range.contains(&value) {
+            return Err(start.error(&format!("integer {value} is out of range {range:?}")));
+        }
+
+        Ok(value)
+    }
+
+    /// Parses `literal`, after the whitespace in front of it.
+    pub fn literal(&mut self, literal: &str) -> Result<(), ParseError> {
+        self.skip_whitespace();
+        self.literal_exact(literal)
+    }
+
+    /// Parses `literal`, which must be right at the position of the cursor.
+    pub fn literal_exact(&mut self, literal: &str) -> Result<(), ParseError> {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        }
+        else {
+            Err(self.error(&format!("expected '{literal}'")))
+        }
+    }
+
+    /// Parses a word made of any characters except whitespace.
+    pub fn word(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+
+        let rest = self.rest();
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("expected a word"));
+        }
+
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Parses `item` until the end of the line.
+    pub fn many<T, F>(&mut self, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut result = Vec::new();
+
+        while !self.is_at_end() {
+            result.push(item(self)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Parses at least one `item`, each of them separated by `separator`.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, ParseError>
+    where
+        F: FnMut(&mut Self) -> Result<T, ParseError>,
+    {
+        let mut result = vec![item(self)?];
+
+        loop {
+            let saved_pos = self.pos;
+            if self.literal(separator).is_err() {
+                self.pos = saved_pos;
+                break;
+            }
+            result.push(item(self)?);
+        }
+
+        Ok(result)
+    }
+
+    /// Verifies that only whitespace remains in the line.
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        if self.pos < self.text.len() {
+            Err(self.error(&format!("unexpected '{}'", self.rest())))
+        }
+        else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(line: usize, column: usize, message: &str) -> ParseError {
+        ParseError::new(line, column, message)
+    }
+
+    #[test]
+    fn integer_in_bounds() {
+        let mut cursor = Cursor::new("  7 -3 12 99999999999", 1);
+
+        assert_eq!(cursor.integer_in(0..=7), Ok(7u32));
+        assert_eq!(cursor.integer_in(-3..0), Ok(-3i32));
+
+        /* The error points at the start of the rejected integer, after the whitespace */
+        assert_eq!(cursor.clone().integer_in::<i32, _>(0..12), Err(error(1, 8, "integer 12 is out of range 0..12")));
+        assert_eq!(cursor.integer_in(12..), Ok(12u8));
+        assert_eq!(cursor.integer_in::<u32, _>(..), Err(error(1, 11, "integer '99999999999' is out of range")));
+        assert_eq!(Cursor::new("x", 4).integer_in::<u32, _>(..), Err(error(4, 1, "expected an integer")));
+        assert_eq!(Cursor::new("-", 1).integer::<i32>(), Err(error(1, 1, "expected an integer")));
+    }
+
+    #[test]
+    fn literals() {
+        let mut cursor = Cursor::new("a: b", 2);
+
+        assert_eq!(cursor.literal_exact("a"), Ok(()));
+        assert_eq!(cursor.literal_exact(":"), Ok(()));
+        assert_eq!(cursor.literal_exact("b"), Err(error(2, 3, "expected 'b'")));
+        assert_eq!(cursor.literal("b"), Ok(()));
+        assert!(cursor.is_at_end());
+    }
+
+    #[test]
+    fn separated_items() {
+        let mut cursor = Cursor::new("1,2 , 3 | 4", 1);
+        assert_eq!(cursor.separated(",", |c| c.integer()), Ok(vec![1, 2, 3]));
+        assert_eq!(cursor.rest(), " | 4");
+
+        assert_eq!(Cursor::new("1,", 1).separated(",", |c| c.integer::<i32>()), Err(error(1, 3, "expected an integer")));
+        assert_eq!(Cursor::new("", 1).separated(",", |c| c.integer::<i32>()), Err(error(1, 1, "expected an integer")));
+    }
+
+    #[test]
+    fn finish() {
+        let mut cursor = Cursor::new("12 x ", 5);
+        cursor.integer::<u8>().unwrap();
+        assert_eq!(cursor.finish(), Err(error(5, 4, "unexpected 'x '")));
+
+        let mut cursor = Cursor::new("12  ", 1);
+        cursor.integer::<u8>().unwrap();
+        assert_eq!(cursor.finish(), Ok(()));
+
+        let lines = parse_lines("1 2\n\n3 4 5\n", |c| Ok((c.integer::<u8>()?, c.integer::<u8>()?)));
+        assert_eq!(lines, Err(error(3, 5, "unexpected '5'")));
+    }
+
+    #[test]
+    fn grid_errors() {
+        let allowed = |c| c == '.' || c == '#';
+
+        assert_eq!(grid("..\n.#\n", "'.' or '#'", allowed).map(|g| g.lines), Ok(vec!["..", ".#"]));
+        assert_eq!(grid("", "'.' or '#'", allowed), Err(error(1, 1, "empty map")));
+        assert_eq!(grid("..\n\n..", "'.' or '#'", allowed), Err(error(2, 1, "unexpected blank line inside the map")));
+        assert_eq!(grid("..\n.x", "'.' or '#'", allowed), Err(error(2, 2, "unexpected character 'x', expected '.' or '#'")));
+        assert_eq!(grid("...\n..", "'.' or '#'", allowed), Err(error(2, 3, "row of width 2, expected 3 like the first row")));
+        assert_eq!(grid("..\n...", "'.' or '#'", allowed), Err(error(2, 3, "row of width 3, expected 2 like the first row")));
+        assert_eq!(single_line("..\n..", "'.'", allowed), Err(error(2, 1, "unexpected second line, expected a single line")));
+    }
+
+    #[test]
+    fn find_unique_errors() {
+        let sections = sections("ab\n\n..\n.^\n^.");
+        let map = &sections[1];
+
+        assert_eq!(sections[0].find_unique('b', "end"), Ok((1, 0)));
+        assert_eq!(map.find_unique('^', "guard"), Err(error(5, 1, "second guard '^', the first one is at line 4, column 2")));
+        assert_eq!(map.find_unique('S', "start"), Err(error(3, 1, "no start 'S' found")));
+    }
+
+    #[test]
+    fn annotate_caret() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n8\n9\nab✓cd";
+
+        assert_eq!(error(10, 4, "unexpected 'c'").annotate(input),
+                   "line 10, column 4: unexpected 'c'\n   |\n10 | ab✓cd\n   |    ^");
+        assert_eq!(error(1, 1, "here").annotate(input), "line 1, column 1: here\n  |\n1 | 1\n  | ^");
+
+        /* Without the line, only the message is given */
+        assert_eq!(error(11, 1, "missing").annotate(input), "line 11, column 1: missing");
+        assert_eq!(error(0, 1, "missing").annotate(input), "line 0, column 1: missing");
+    }
+
+    #[test]
+    fn exact_sections() {
+        let names = ["the rules", "the updates"];
+
+        let sections = sections_exact("1|2\n\n\n1,2\n3,4\n", &names).unwrap();
+        assert_eq!(sections.iter().map(|s| s.first_line).collect::<Vec<_>>(), [1, 4]);
+        assert_eq!(sections[1].lines, ["1,2", "3,4"]);
+
+        assert_eq!(sections_exact("1|2\n2|3\n", &names),
+                   Err(error(3, 1, "missing the updates, expected the rules and the updates separated by a blank line")));
+        assert_eq!(sections_exact("", &names),
+                   Err(error(1, 1, "missing the rules, expected the rules and the updates separated by a blank line")));
+        assert_eq!(sections_exact("a\n\nb\n\nc", &names),
+                   Err(error(5, 1, "unexpected section, expected the rules and the updates separated by a blank line")));
+        assert_eq!(sections_exact("a\n\nb\n\nc\n\nd", &["x", "y", "z"]).unwrap_err().message,
+                   "unexpected section, expected x, y and z separated by a blank line");
+        assert_eq!(sections_exact("a\n\nb", &["the map"]), Err(error(3, 1, "unexpected section, expected only the map")));
+    }
+}
@@ -0,0 +1,217 @@
+/* Timing of named spans inside a solver, to find where the time of a part goes.
+ *
+ * A solver opens a span with `Context::span`, which is timed until the returned guard is dropped.
+ * The spans opened while another one is open are its children, and the spans of the same name
+ * under the same parent are aggregated: their count and their total time make the profile. The
+ * self time of a span is its total time minus the total time of its children.
+ *
+ * Without `--profile`, a span is an empty guard. The spans are meant for the thread of the solver:
+ * the ones of other threads would be mixed with its own.
+ */
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Aggregated span of the profile.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanStats {
+    pub name: String,
+    /// Number of parents of the span, 0 for the spans opened directly by the solver
+    pub depth: usize,
+    pub count: u64,
+    pub total: Duration,
+    /// Time spent in the span outside of its children
+    pub self_time: Duration,
+}
+
+#[derive(Debug)]
+struct Node {
+    name: String,
+    parent: Option<usize>,
+    count: u64,
+    total: Duration,
+}
+
+/// Tree of the spans of a run.
+#[derive(Debug, Default)]
+pub(crate) struct Profiler {
+    nodes: Vec<Node>,
+    /// Spans currently open, the innermost last
+    open: Vec<usize>,
+}
+
+impl Profiler {
+    fn enter(&mut self, name: &str) -> usize {
+        let parent = self.open.last().copied();
+        let id = match self.nodes.iter().position(|n| n.parent == parent && n.name == name) {
+            Some(id) => id,
+            None => {
+                self.nodes.push(Node { name: name.to_string(), parent, count: 0, total: Duration::ZERO });
+                self.nodes.len() - 1
+            },
+        };
+
+        self.open.push(id);
+        id
+    }
+
+    fn exit(&mut self, id: usize, elapsed: Duration) {
+        let node = &mut self.nodes[id];
+        node.count += 1;
+        node.total += elapsed;
+
+        /* The guards are dropped in reverse order, unless one of them is moved elsewhere */
+        if let Some(idx) = self.open.iter().rposition(|open| *open == id) {
+            self.open.truncate(idx);
+        }
+    }
+
+    /// Spans in the order of the tree, each one followed by its children.
+    pub(crate) fn stats(&self) -> Vec<SpanStats> {
+        let mut stats = Vec::new();
+        self.add_children(None, 0, &mut stats);
+        stats
+    }
+
+    fn add_children(&self, parent: Option<usize>, depth: usize, stats: &mut Vec<SpanStats>) {
+        for (id, node) in self.nodes.iter().enumerate().filter(|(_, n)| n.parent == parent) {
+            let children: Duration = self.nodes.iter().filter(|n| n.parent == Some(id)).map(|n| n.total).sum();
+            stats.push(SpanStats {
+                name: node.name.clone(),
+                depth,
+                count: node.count,
+                total: node.total,
+                self_time: node.total.saturating_sub(children),
+            });
+            self.add_children(Some(id), depth + 1, stats);
+        }
+    }
+}
+
+/// Guard of an open span, which is timed until it is dropped.
+#[must_use = "the span is closed as soon as its guard is dropped"]
+pub struct Span<'a> {
+    profiler: Option<(&'a Mutex<Profiler>, usize, Instant)>,
+}
+
+impl<'a> Span<'a> {
+    pub(crate) fn open(profiler: Option<&'a Mutex<Profiler>>, name: &str) -> Self {
+        Self { profiler: profiler.map(|p| (p, p.lock().unwrap().enter(name), Instant::now())) }
+    }
+}
+
+impl Drop for Span<'_> {
+    fn drop(&mut self) {
+        if let Some((profiler, id, start)) = self.profiler.take() {
+            let elapsed = start.elapsed();
+            profiler.lock().unwrap().exit(id, elapsed);
+        }
+    }
+}
+
+/// Prints the profile as a tree, with the share of each span in the time of the part.
+pub fn print(stats: &[SpanStats], elapsed: Duration) {
+    print!("{}", render(stats, elapsed));
+}
+
+/// Profile as a tree, a line per span: its count, total time, self time and share of `elapsed`.
+pub fn render(stats: &[SpanStats], elapsed: Duration) -> String {
+    let width = stats.iter().map(|s| 2 * s.depth + s.name.chars().count()).max().unwrap_or_default();
+
+    let mut text = format!("Profile:\n  {:<width$}  {:>9}    {:>12}  {:>12}  {:>6}\n", "", "count", "total", "self", "share");
+    for span in stats {
+        let name = format!("{}{}", "  ".repeat(span.depth), span.name);
+        let share = match elapsed.is_zero() {
+            true => 0.0,
+            false => 100.0 * span.total.as_secs_f64() / elapsed.as_secs_f64(),
+        };
+        text += &format!("  {name:<width$}  {:>9} x  {:>12}  {:>12}  {share:5.1}%\n", span.count, format!("{:.2?}", span.total),
+                         format!("{:.2?}", span.self_time));
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{CancelToken, Context};
+    use std::sync::Arc;
+    use std::thread;
+
+    const PAUSE: Duration = Duration::from_millis(20);
+
+    fn profiled_context() -> (Context, Arc<Mutex<Profiler>>) {
+        let profiler = Arc::new(Mutex::new(Profiler::default()));
+        (Context::new(CancelToken::default()).with_profiler(Arc::clone(&profiler)), profiler)
+    }
+
+    fn span(name: &str, depth: usize, count: u64, total_ms: u64, self_ms: u64) -> SpanStats {
+        SpanStats {
+            name: name.to_string(),
+            depth,
+            count,
+            total: Duration::from_millis(total_ms),
+            self_time: Duration::from_millis(self_ms),
+        }
+    }
+
+    #[test]
+    fn nested_spans() {
+        let (ctx, profiler) = profiled_context();
+        {
+            let _parse = ctx.span("parse");
+        }
+        {
+            let _solve = ctx.span("solve");
+            for _ in 0..3 {
+                let _step = ctx.span("step");
+                let _inner = ctx.span("inner");
+            }
+            let _parse = ctx.span("parse");
+        }
+
+        let stats = profiler.lock().unwrap().stats();
+        let tree: Vec<(&str, usize, u64)> = stats.iter().map(|s| (s.name.as_str(), s.depth, s.count)).collect();
+        assert_eq!(tree, [("parse", 0, 1), ("solve", 0, 1), ("step", 1, 3), ("inner", 2, 3), ("parse", 1, 1)]);
+    }
+
+    #[test]
+    fn self_and_total_times() {
+        let (ctx, profiler) = profiled_context();
+        {
+            let _solve = ctx.span("solve");
+            thread::sleep(PAUSE);
+            for _ in 0..2 {
+                let _step = ctx.span("step");
+                thread::sleep(PAUSE);
+            }
+        }
+
+        let stats = profiler.lock().unwrap().stats();
+        let [solve, step] = &stats[..] else { panic!("{stats:?}") };
+        assert_eq!((step.count, step.self_time), (2, step.total));
+        assert!(step.total >= 2 * PAUSE, "{step:?}");
+        assert!(solve.total >= 3 * PAUSE, "{solve:?}");
+        assert_eq!(solve.self_time, solve.total - step.total);
+        assert!(solve.self_time >= PAUSE, "{solve:?}");
+    }
+
+    #[test]
+    fn without_profile() {
+        let ctx = Context::new(CancelToken::default());
+        let span = ctx.span("solve");
+        assert!(span.profiler.is_none());
+    }
+
+    #[test]
+    fn rendered_tree() {
+        let stats = [span("parse", 0, 1, 2, 2), span("solve", 0, 1, 90, 10), span("step", 1, 4000, 80, 80)];
+        assert_eq!(render(&stats, Duration::from_millis(100)), "\
+Profile:
+              count           total          self   share
+  parse           1 x        2.00ms        2.00ms    2.0%
+  solve           1 x       90.00ms       10.00ms   90.0%
+    step       4000 x       80.00ms       80.00ms   80.0%
+");
+        assert!(render(&stats, Duration::ZERO).lines().all(|l| !l.ends_with('%') || l.ends_with("  0.0%")));
+    }
+}
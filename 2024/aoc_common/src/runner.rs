@@ -0,0 +1,601 @@
+/* Runs the solver of a part and reports how it went.
+ *
+ * The solver runs in its own thread so that it can be given a timeout. Stopping is cooperative:
+ * when the timeout expires the cancel token of the `Context` is set, and the solvers doing long
+ * searches are expected to call `Context::check_cancelled` regularly to abort cleanly.
+ *
+ * Those solvers can also save a checkpoint of their search with `Context::checkpoint`. The
+ * checkpoint is kept when the run is interrupted, and given back to the solver through
+ * `Context::resumed_checkpoint` when the run is resumed.
+ *
+ * The grid simulations record the frames of their animation with `Context::frame`.
+ *
+ * When the allocations are counted, the runner measures them for the whole part, and the solvers
+ * can measure some phases such as the parsing with `Context::measure`.
+ *
+ * With `--profile`, the spans opened by the solver with `Context::span`, and its measured phases,
+ * are timed and printed as a tree, see `profile`.
+ */
+use crate::alloc::{self, AllocStats, Measure};
+use crate::animation::{self, Frame, FrameRecorder, PlaybackOptions};
+use crate::answer::Answer;
+use crate::args::Args;
+use crate::checkpoint::{self, Checkpoint, CheckpointStore};
+use crate::image;
+use crate::input::{self, InputSource};
+use crate::profile::{self, Profiler, Span, SpanStats};
+use crate::sandbox::{self, Limits};
+use std::fmt;
+use std::io::{IsTerminal, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Signature of the function solving a part of a puzzle.
+pub type Solver = fn(&str, &Context) -> Result<Answer, String>;
+
+/// Time left to a solver to stop after having been cancelled.
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Minimum time between two refreshes of the progress bar.
+const PROGRESS_REFRESH: Duration = Duration::from_millis(100);
+
+/// Error message returned by the solvers when they are cancelled.
+pub const CANCELLED: &str = "cancelled";
+
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+type ProgressCallback = Box<dyn Fn(u64, u64) + Send + Sync>;
+type AllocPhases = Arc<Mutex<Vec<(String, AllocStats)>>>;
+
+/// What the runner gives to a solver besides its input.
+#[derive(Default)]
+pub struct Context {
+    cancel_token: CancelToken,
+    progress: Option<ProgressCallback>,
+    checkpoints: Option<CheckpointStore>,
+    frames: Option<Arc<Mutex<FrameRecorder>>>,
+    alloc_phases: Option<AllocPhases>,
+    profiler: Option<Arc<Mutex<Profiler>>>,
+}
+
+impl Context {
+    pub fn new(cancel_token: CancelToken) -> Self {
+        Self { cancel_token, progress: None, checkpoints: None, frames: None, alloc_phases: None, profiler: None }
+    }
+
+    pub fn with_checkpoints(mut self, checkpoints: CheckpointStore) -> Self {
+        self.checkpoints = Some(checkpoints);
+        self
+    }
+
+    fn with_frames(mut self, frames: Arc<Mutex<FrameRecorder>>) -> Self {
+        self.frames = Some(frames);
+        self
+    }
+
+    fn with_alloc_phases(mut self, alloc_phases: AllocPhases) -> Self {
+        self.alloc_phases = Some(alloc_phases);
+        self
+    }
+
+    pub(crate) fn with_profiler(mut self, profiler: Arc<Mutex<Profiler>>) -> Self {
+        self.profiler = Some(profiler);
+        self
+    }
+
+    /// Calls `callback` with the `done` and `total` values given to `progress`.
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(u64, u64) + Send + Sync + 'static,
+    {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel_token
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_token.is_cancelled()
+    }
+
+    /// Returns an error when the solver has been cancelled, to be used with `?`.
+    pub fn check_cancelled(&self) -> Result<(), String> {
+        if self.is_cancelled() {
+            Err(CANCELLED.to_string())
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    /// Reports that `done` steps out of `total` have been done.
+    pub fn progress(&self, done: u64, total: u64) {
+        if let Some(callback) = &self.progress {
+            callback(done, total);
+        }
+    }
+
+    /// Checkpoint saved by an interrupted run, when the run is resumed
+    pub fn resumed_checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoints.as_ref().and_then(|c| c.resumed())
+    }
+
+    /// Saves the checkpoint built by `build` from time to time, and always once cancelled so
+    /// that the run can be resumed from where it stopped.
+    pub fn checkpoint<F>(&self, build: F)
+    where
+        F: FnOnce() -> Checkpoint,
+    {
+        if let Some(Err(e)) = self.checkpoints.as_ref().map(|c| c.save(self.is_cancelled(), build)) {
+            eprintln!("{e}");
+        }
+    }
+
+    /// Whether the frames of the animation are recorded, for the solvers which need to prepare them.
+    pub fn records_frames(&self) -> bool {
+        self.frames.is_some()
+    }
+
+    /// Records the frame built by `build`, which is only called when the animation is recorded.
+    /// Some frames are skipped when there are too many of them.
+    pub fn frame<F>(&self, build: F)
+    where
+        F: FnOnce() -> Frame,
+    {
+        if let Some(frames) = &self.frames {
+            frames.lock().unwrap().record(build, false);
+        }
+    }
+
+    /// Same as `frame` for the frames which must stay in the animation, such as its first or last
+    /// frame: they are never skipped nor dropped.
+    pub fn last_frame<F>(&self, build: F)
+    where
+        F: FnOnce() -> Frame,
+    {
+        if let Some(frames) = &self.frames {
+            frames.lock().unwrap().record(build, true);
+        }
+    }
+
+    /// Opens the span `name`, timed until the returned guard is dropped when the run is profiled:
+    /// `let _span = ctx.span("simulation");`.
+    pub fn span(&self, name: &str) -> Span<'_> {
+        Span::open(self.profiler.as_deref(), name)
+    }
+
+    /// Runs `f`, a phase of the solver named `name`, measuring its allocations when they are counted.
+    /// The measures of the phases run several times are added together. The phase is also a span.
+    pub fn measure<T, F>(&self, name: &str, f: F) -> T
+    where
+        F: FnOnce() -> T,
+    {
+        let _span = self.span(name);
+        match &self.alloc_phases {
+            Some(phases) => {
+                let (result, stats) = alloc::measure(f);
+                let mut phases = phases.lock().unwrap();
+                match phases.iter_mut().find(|(n, _)| n == name) {
+                    Some((_, total)) => total.add(&stats),
+                    None => phases.push((name.to_string(), stats)),
+                }
+                result
+            },
+            None => f(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    Failed(String),
+    Panicked(String),
+    TimedOut(Duration),
+    /// Stopped by the memory limit of the sandbox, in bytes
+    ExceededMemory(u64),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) if answer.is_multiline() => write!(f, "\n{answer}"),
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::Failed(e) => write!(f, "failed: {e}"),
+            Outcome::Panicked(e) => write!(f, "panicked: {e}"),
+            Outcome::TimedOut(t) => write!(f, "timed out after {t:?}"),
+            Outcome::ExceededMemory(limit) => write!(f, "exceeded memory limit of {} MiB", limit >> 20),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PartReport {
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+    /// Frames of the animation, when recorded
+    pub frames: Vec<Frame>,
+    /// Allocations of the part, when they are counted
+    pub allocations: Option<AllocStats>,
+    /// Allocations of the phases measured by the solver
+    pub alloc_phases: Vec<(String, AllocStats)>,
+    /// Spans of the solver, when profiled
+    pub profile: Vec<SpanStats>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct RunOptions {
+    pub timeout: Option<Duration>,
+    /// Label of the progress bar, which is only drawn when stderr is a terminal
+    pub progress_label: Option<String>,
+    /// File where the solver saves its checkpoints
+    pub checkpoint: Option<PathBuf>,
+    /// Resume from the checkpoint file, if it exists
+    pub resume: bool,
+    /// Record the frames of the animation of the solver
+    pub record_frames: bool,
+    /// Time the spans of the solver
+    pub profile: bool,
+}
+
+/// Runs `solver` over `input` with the given options.
+pub fn run_part(solver: Solver, input: &str, options: &RunOptions) -> PartReport {
+    let cancel_token = CancelToken::new();
+    let mut ctx = Context::new(cancel_token.clone());
+    if let Some(path) = &options.checkpoint {
+        match CheckpointStore::open(path, input, options.resume) {
+            Ok(checkpoints) => ctx = ctx.with_checkpoints(checkpoints),
+            Err(e) => return PartReport {
+                outcome: Outcome::Failed(e),
+                elapsed: Duration::ZERO,
+                frames: Vec::new(),
+                allocations: None,
+                alloc_phases: Vec::new(),
+                profile: Vec::new(),
+            },
+        }
+    }
+    let recorder = options.record_frames.then(|| Arc::new(Mutex::new(FrameRecorder::new())));
+    if let Some(recorder) = &recorder {
+        ctx = ctx.with_frames(Arc::clone(recorder));
+    }
+    let alloc_phases = alloc::is_enabled().then(AllocPhases::default);
+    if let Some(phases) = &alloc_phases {
+        ctx = ctx.with_alloc_phases(Arc::clone(phases));
+    }
+    let profiler = options.profile.then(|| Arc::new(Mutex::new(Profiler::default())));
+    if let Some(profiler) = &profiler {
+        ctx = ctx.with_profiler(Arc::clone(profiler));
+    }
+    let progress_bar = options.progress_label.as_ref()
+                                             .filter(|_| std::io::stderr().is_terminal())
+                                             .map(|label| Arc::new(ProgressBar::new(label)));
+    if let Some(bar) = &progress_bar {
+        let bar = Arc::clone(bar);
+        ctx = ctx.with_progress(move |done, total| bar.draw(done, total));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_string();
+    let start = Instant::now();
+    let measure = alloc::is_enabled().then(Measure::start);
+
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solver(&input, &ctx)));
+
+        /* The checkpoint is useless once the part is solved */
+        if let (Ok(Ok(_)), Some(checkpoints)) = (&result, &ctx.checkpoints) {
+            checkpoints.remove();
+        }
+
+        /* The runner may have stopped waiting for the result */
+        let _ = sender.send(result);
+    });
+
+    let received = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|_| ()),
+        None => receiver.recv().map_err(|_| ()),
+    };
+
+    let outcome = match received {
+        Ok(Ok(Ok(answer))) => Outcome::Solved(answer),
+        Ok(Ok(Err(e))) => Outcome::Failed(e),
+        Ok(Err(payload)) => Outcome::Panicked(panic_message(payload.as_ref())),
+        Err(_) => {
+            /* Give the solver a chance to stop cleanly, else leave the thread behind */
+            cancel_token.cancel();
+            let _ = receiver.recv_timeout(CANCEL_GRACE_PERIOD);
+            Outcome::TimedOut(options.timeout.unwrap_or_default())
+        },
+    };
+    let elapsed = start.elapsed();
+    let allocations = measure.map(Measure::finish);
+
+    if let Some(bar) = progress_bar {
+        bar.clear();
+    }
+
+    let frames = recorder.map(|r| r.lock().unwrap().take()).unwrap_or_default();
+    let alloc_phases = alloc_phases.map(|p| p.lock().unwrap().clone()).unwrap_or_default();
+
+    let profile = profiler.map(|p| p.lock().unwrap().stats()).unwrap_or_default();
+
+    PartReport { outcome, elapsed, frames, allocations, alloc_phases, profile }
+}
+
+/// Runs `solver` on the current thread, without timeout nor timing, for the targets which have
+/// neither threads nor clock such as WebAssembly.
+pub fn run_inline(solver: Solver, input: &str, record_frames: bool) -> (Outcome, Vec<Frame>) {
+    let recorder = record_frames.then(|| Arc::new(Mutex::new(FrameRecorder::new())));
+    let mut ctx = Context::default();
+    if let Some(recorder) = &recorder {
+        ctx = ctx.with_frames(Arc::clone(recorder));
+    }
+
+    let outcome = match panic::catch_unwind(AssertUnwindSafe(|| solver(input, &ctx))) {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(e)) => Outcome::Failed(e),
+        Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+    };
+    let frames = recorder.map(|r| r.lock().unwrap().take()).unwrap_or_default();
+
+    (outcome, frames)
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    }
+    else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    }
+    else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Progress bar drawn on stderr.
+struct ProgressBar {
+    label: String,
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl ProgressBar {
+    const WIDTH: u64 = 40;
+
+    fn new(label: &str) -> Self {
+        Self { label: label.to_string(), last_draw: Mutex::new(None) }
+    }
+
+    fn draw(&self, done: u64, total: u64) {
+        let mut last_draw = self.last_draw.lock().unwrap();
+        if last_draw.is_some_and(|t| t.elapsed() < PROGRESS_REFRESH) && done < total {
+            return;
+        }
+        *last_draw = Some(Instant::now());
+
+        let total = total.max(1);
+        let done = done.min(total);
+        let filled = (done * Self::WIDTH / total) as usize;
+        eprint!("\r{} [{}{}] {:3}% ({done}/{total})", self.label,
+                "#".repeat(filled), " ".repeat(Self::WIDTH as usize - filled), done * 100 / total);
+        let _ = std::io::stderr().flush();
+    }
+
+    fn clear(&self) {
+        if self.last_draw.lock().unwrap().is_some() {
+            eprint!("\r\x1b[2K");
+            let _ = std::io::stderr().flush();
+        }
+    }
+}
+
+/// Options of the binaries of each day which take a value.
+pub const VALUE_OPTIONS: &[&str] = &["--timeout", "--fps", "--frames-out", "--render", "--cpu-limit", "--memory-limit"];
+
+/// Entry point of the binary of a day: solves the part and prints the answer after `label`.
+///
+/// The input is the file given as argument, `-` for the standard input. Without argument, it is
+/// the standard input if it is a pipe or a file, else `filename`.
+///
+/// Also accepts `--timeout <duration>` to stop the solver after the given time, `--resume` to
+/// resume an interrupted run from its checkpoint, the options of `PlaybackOptions` to
+/// animate the simulation and `--render <FILE>` to export it as images. `--sandbox`, or one of
+/// `--cpu-limit <duration>` and `--memory-limit <size>`, solves the part in a child process, see
+/// `sandbox`. `--profile` prints the time spent in the spans of the solver.
+pub fn run_main(filename: &str, label: &str, solver: Solver) {
+    run_main_from(InputSource::File(PathBuf::from(filename)), label, solver);
+}
+
+/// Same as `run_main`, the default input being compiled into the binary, see the `embed-input`
+/// feature of the days.
+pub fn run_main_embedded(input: &'static str, label: &str, solver: Solver) {
+    run_main_from(InputSource::Embedded(input), label, solver);
+}
+
+fn run_main_from(default: InputSource, label: &str, solver: Solver) {
+    /* Child started by the sandbox: the input comes from the parent */
+    if sandbox::is_worker() {
+        if let Err(e) = input::read_stdin().and_then(|input| sandbox::run_worker(solver, &input)) {
+            eprintln!("{e}");
+        }
+        return;
+    }
+
+    let args = match Args::from_env(VALUE_OPTIONS) {
+        Ok(a) => a,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+    let timeout = match args.duration("--timeout") {
+        Ok(t) => t,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+    let playback = match PlaybackOptions::from_args(&args) {
+        Ok(p) => p,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+    let render = match RenderOptions::from_args(&args) {
+        Ok(r) => r,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+    let limits = match Limits::from_args(&args) {
+        Ok(l) => l,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+    let sandboxed = args.flag("--sandbox") || limits.cpu.is_some() || limits.memory.is_some();
+    if sandboxed && (playback.is_some() || render.is_some() || args.flag("--resume") || args.flag("--profile")) {
+        println!("The sandbox cannot be combined with '--resume', '--profile' nor an animation");
+        return;
+    }
+
+    let source = InputSource::select_or(args.positional.first().map(|a| a.as_str()), default);
+    if matches!(&source, InputSource::File(path) if !path.is_file()) {
+        println!("File '{source}' not found.");
+        return;
+    }
+    let input = match source.read() {
+        Ok(i) => i,
+        Err(e) => {
+            println!("{e}");
+            return;
+        },
+    };
+
+    /* The binary is named after the crate, e.g. aoc_06_part2 */
+    let name = std::env::args().next()
+                               .and_then(|p| Path::new(&p).file_stem().map(|s| s.to_string_lossy().to_string()))
+                               .unwrap_or("checkpoint".to_string());
+
+    let options = RunOptions {
+        timeout,
+        progress_label: Some(label.to_string()),
+        checkpoint: Some(checkpoint::checkpoint_file(&name)),
+        resume: args.flag("--resume"),
+        record_frames: playback.is_some() || render.is_some(),
+        profile: args.flag("--profile"),
+    };
+    let report = match (sandboxed, std::env::current_exe()) {
+        (true, Ok(exe)) => sandbox::run_in_child(std::process::Command::new(exe), &input, &limits),
+        (true, Err(e)) => {
+            println!("Could not find the binary to run in the sandbox: {e}");
+            return;
+        },
+        (false, _) => run_part(solver, &input, &options),
+    };
+    match &report.outcome {
+        Outcome::Solved(answer) if answer.is_multiline() => println!("{label}:\n{answer}"),
+        Outcome::Solved(answer) => println!("{label}: {answer}"),
+        Outcome::Failed(e) => println!("{e}"),
+        outcome => println!("{label}: {outcome}"),
+    }
+
+    print_allocations(&report);
+    print_profile(&report);
+
+    if let Some(render) = render {
+        render_frames(&report.frames, &render);
+    }
+    if let Some(playback) = playback {
+        play_frames(&report.frames, &playback);
+    }
+}
+
+/// Prints the allocations of the part and of its phases, when they are counted, with the peak of
+/// the resident memory of the process so far.
+pub fn print_allocations(report: &PartReport) {
+    if let Some(allocations) = &report.allocations {
+        match alloc::peak_rss() {
+            Some(rss) => println!("Allocations: {allocations} (peak RSS of the process {})", alloc::format_bytes(rss)),
+            None => println!("Allocations: {allocations}"),
+        }
+        for (name, stats) in &report.alloc_phases {
+            println!("  {name}: {stats}");
+        }
+    }
+}
+
+pub fn print_profile(report: &PartReport) {
+    if !report.profile.is_empty() {
+        profile::print(&report.profile, report.elapsed);
+    }
+}
+
+/// Export of the frames of a run as images.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RenderOptions {
+    pub path: PathBuf,
+    pub fps: f64,
+}
+
+impl RenderOptions {
+    /// Reads `--render <FILE>`, with the speed of the animation given by `--fps <N>`.
+    pub fn from_args(args: &Args) -> Result<Option<Self>, String> {
+        let Some(path) = args.value("--render") else {
+            return Ok(None);
+        };
+
+        let fps = args.parsed_value("--fps")?.unwrap_or(animation::DEFAULT_FPS);
+        Ok(Some(Self { path: PathBuf::from(path), fps }))
+    }
+}
+
+/// Renders the frames recorded by a solver as images, reporting the outcome on stdout.
+pub fn render_frames(frames: &[Frame], render: &RenderOptions) {
+    if frames.is_empty() {
+        println!("No animation recorded by this solver");
+        return;
+    }
+
+    match image::render_frames(frames, &render.path, render.fps, image::DEFAULT_SCALE) {
+        Ok(files) if files.len() == 1 => println!("Rendered {} frames to '{}'", frames.len(), files[0].display()),
+        Ok(files) => println!("Rendered {} frames to '{}' ... '{}'", frames.len(), files[0].display(),
+                              files[files.len() - 1].display()),
+        Err(e) => println!("{e}"),
+    }
+}
+
+/// Plays the frames recorded by a solver, reporting the errors on stdout.
+pub fn play_frames(frames: &[Frame], playback: &PlaybackOptions) {
+    if frames.is_empty() {
+        println!("No animation recorded by this solver");
+    }
+    else if let Err(e) = animation::play(frames, playback) {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,310 @@
+/* Runs a part in a child process with limits, so that a buggy solver cannot freeze the machine.
+ *
+ * The child is a worker: the same binary started with the variable `AOC_SANDBOX_RESULT`, which
+ * reads the input on its standard input, solves the part with `run_worker` and writes the outcome
+ * to the file named by the variable. Its logs go to the terminal as they are written, the parent
+ * only keeping their end to find why the child crashed.
+ *
+ * The wall time is enforced by the parent, which kills the child when it expires. The CPU time
+ * and the address space are limits of the child process (`setrlimit`, on Unix only): a child
+ * stopped by SIGXCPU, or killed once its CPU time reached the limit, timed out, and one which
+ * reported a failed allocation exceeded its memory. Any other crash is a failure.
+ */
+use crate::answer::Answer;
+use crate::args::Args;
+use crate::runner::{self, Outcome, PartReport, RunOptions, Solver};
+use std::fs;
+use std::io::{Read, Write};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Variable naming the result file of a worker.
+pub const RESULT_VARIABLE: &str = "AOC_SANDBOX_RESULT";
+
+/// Time between two checks of the child.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Message printed by the standard library when an allocation fails.
+const ALLOCATION_FAILED: &str = "memory allocation of";
+
+/// Bytes kept from the end of the logs of the child, where the allocation failure is reported.
+const STDERR_TAIL: usize = 4096;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Limits {
+    pub wall: Option<Duration>,
+    pub cpu: Option<Duration>,
+    /// Address space of the child, in bytes
+    pub memory: Option<u64>,
+}
+
+impl Limits {
+    /// Reads `--timeout`, `--cpu-limit` and `--memory-limit`.
+    pub fn from_args(args: &Args) -> Result<Self, String> {
+        let limits = Self { wall: args.duration("--timeout")?, cpu: args.duration("--cpu-limit")?, memory: args.size("--memory-limit")? };
+        if !cfg!(unix) && (limits.cpu.is_some() || limits.memory.is_some()) {
+            return Err("The CPU and memory limits are only supported on Unix".to_string());
+        }
+        Ok(limits)
+    }
+}
+
+/// Whether this process is a worker started by `run_in_child`.
+pub fn is_worker() -> bool {
+    std::env::var_os(RESULT_VARIABLE).is_some()
+}
+
+/// Solves the part as a worker: the outcome and the run time are written to the result file as
+/// `<outcome> <nanoseconds>`, with the kind of the answer when solved, see `Answer::kind`, followed
+/// by the answer or the error on the next lines.
+pub fn run_worker(solver: Solver, input: &str) -> Result<(), String> {
+    let path = std::env::var(RESULT_VARIABLE).map_err(|_| format!("Missing variable '{RESULT_VARIABLE}'"))?;
+    let report = runner::run_part(solver, input, &RunOptions::default());
+
+    fs::write(&path, result_text(&report.outcome, report.elapsed)).map_err(|e| format!("Could not write '{path}': {e}"))
+}
+
+fn result_text(outcome: &Outcome, elapsed: Duration) -> String {
+    let (header, text) = match outcome {
+        Outcome::Solved(answer) => (format!("solved {} {}", elapsed.as_nanos(), answer.kind()), answer.to_string()),
+        Outcome::Failed(e) => (format!("failed {}", elapsed.as_nanos()), e.clone()),
+        Outcome::Panicked(e) => (format!("panicked {}", elapsed.as_nanos()), e.clone()),
+        outcome => (format!("failed {}", elapsed.as_nanos()), outcome.to_string()),
+    };
+    format!("{header}\n{text}")
+}
+
+fn parse_result(text: &str) -> Option<(Outcome, Duration)> {
+    let (header, text) = text.split_once('\n')?;
+    let fields: Vec<&str> = header.split(' ').collect();
+
+    let outcome = match fields[..] {
+        ["solved", _, kind] => Outcome::Solved(Answer::from_kind(kind, text)?),
+        ["failed", _] => Outcome::Failed(text.to_string()),
+        ["panicked", _] => Outcome::Panicked(text.to_string()),
+        _ => return None,
+    };
+    Some((outcome, Duration::from_nanos(fields[1].parse().ok()?)))
+}
+
+/// Outcome of a child which did not write its result, from the end of its logs and its CPU time.
+fn crash_outcome(status: ExitStatus, stderr_tail: &str, cpu_time: Duration, limits: &Limits) -> Outcome {
+    if let (Some(limit), true) = (limits.memory, stderr_tail.contains(ALLOCATION_FAILED)) {
+        return Outcome::ExceededMemory(limit);
+    }
+    if let Some(limit) = limits.cpu.filter(|limit| platform::killed_by_cpu_limit(status, cpu_time, *limit)) {
+        return Outcome::TimedOut(limit);
+    }
+    Outcome::Failed(format!("the child process crashed ({status})"))
+}
+
+/// Copies the logs of the child to `output` as they come, returns their last bytes.
+fn forward_logs(mut pipe: impl Read, mut output: impl Write) -> String {
+    let mut tail: Vec<u8> = Vec::new();
+    let mut buffer = [0; 4096];
+
+    while let Ok(count @ 1..) = pipe.read(&mut buffer) {
+        let _ = output.write_all(&buffer[..count]);
+        tail.extend_from_slice(&buffer[..count]);
+        tail.drain(..tail.len().saturating_sub(STDERR_TAIL));
+    }
+
+    String::from_utf8_lossy(&tail).into_owned()
+}
+
+/// Runs `command`, a worker of the part, on `input` within the `limits`.
+pub fn run_in_child(command: Command, input: &str, limits: &Limits) -> PartReport {
+    run_in_child_with_logs(command, input, limits, std::io::stderr())
+}
+
+/// Same as `run_in_child`, the logs of the child being copied to `logs`.
+pub fn run_in_child_with_logs(mut command: Command, input: &str, limits: &Limits, logs: impl Write + Send + 'static) -> PartReport {
+    static RUNS: AtomicUsize = AtomicUsize::new(0);
+    let result_file = std::env::temp_dir().join(format!("aoc_sandbox_{}_{}.txt", std::process::id(),
+                                                        RUNS.fetch_add(1, Ordering::Relaxed)));
+    let report = |outcome, elapsed| PartReport {
+        outcome, elapsed, frames: Vec::new(), allocations: None, alloc_phases: Vec::new(), profile: Vec::new(),
+    };
+
+    command.env(RESULT_VARIABLE, &result_file).stdin(Stdio::piped()).stderr(Stdio::piped());
+    platform::set_limits(&mut command, limits);
+
+    /* The children are run one at a time, so the CPU time of the waited ones grows by the one of
+     * this child */
+    let children_cpu = platform::children_cpu_time();
+    let start = Instant::now();
+    let mut child = match command.spawn() {
+        Ok(child) => child,
+        Err(e) => return report(Outcome::Failed(format!("Could not start the child process: {e}")), Duration::ZERO),
+    };
+
+    /* Both pipes are handled by threads, so that a large input or output cannot block the child */
+    let mut stdin = child.stdin.take();
+    let input = input.to_string();
+    thread::spawn(move || stdin.as_mut().map(|s| s.write_all(input.as_bytes())));
+    let stderr = child.stderr.take().map(|pipe| thread::spawn(move || forward_logs(pipe, logs)));
+
+    let status = wait(&mut child, limits.wall);
+    let cpu_time = platform::children_cpu_time().saturating_sub(children_cpu);
+    let stderr_tail = stderr.and_then(|s| s.join().ok()).unwrap_or_default();
+
+    let (outcome, elapsed) = match status {
+        None => (Outcome::TimedOut(limits.wall.unwrap_or_default()), start.elapsed()),
+        Some(status) => fs::read_to_string(&result_file).ok().and_then(|text| parse_result(&text))
+                            .unwrap_or_else(|| (crash_outcome(status, &stderr_tail, cpu_time, limits), start.elapsed())),
+    };
+    let _ = fs::remove_file(&result_file);
+
+    report(outcome, elapsed)
+}
+
+/// Waits for the child, which is killed when `timeout` expires. Returns `None` in that case.
+fn wait(child: &mut Child, timeout: Option<Duration>) -> Option<ExitStatus> {
+    /* A deadline too far to be represented is never reached */
+    let deadline = timeout.and_then(|t| Instant::now().checked_add(t));
+
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => return Some(status),
+            Ok(None) if deadline.is_some_and(|d| Instant::now() >= d) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return None;
+            },
+            Ok(None) => thread::sleep(POLL_INTERVAL),
+            Err(_) => return None,
+        }
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use super::Limits;
+    use std::os::unix::process::{CommandExt, ExitStatusExt};
+    use std::process::{Command, ExitStatus};
+    use std::time::Duration;
+
+    pub(super) fn set_limits(command: &mut Command, limits: &Limits) {
+        let cpu = limits.cpu.map(|t| t.as_secs_f64().ceil().max(1.0) as libc::rlim_t);
+        let memory = limits.memory.map(|m| m as libc::rlim_t);
+
+        /* Only `setrlimit` runs between the fork and the exec, which is async-signal-safe */
+        unsafe {
+            command.pre_exec(move || {
+                /* The hard limit is one second later, to kill the child if it ignores SIGXCPU */
+                if let Some(cpu) = cpu {
+                    check(libc::setrlimit(libc::RLIMIT_CPU, &libc::rlimit { rlim_cur: cpu, rlim_max: cpu.saturating_add(1) }))?;
+                }
+                if let Some(memory) = memory {
+                    check(libc::setrlimit(libc::RLIMIT_AS, &libc::rlimit { rlim_cur: memory, rlim_max: memory }))?;
+                }
+                Ok(())
+            });
+        }
+    }
+
+    fn check(result: libc::c_int) -> std::io::Result<()> {
+        match result {
+            0 => Ok(()),
+            _ => Err(std::io::Error::last_os_error()),
+        }
+    }
+
+    /// SIGXCPU at the soft limit, or SIGKILL at the hard one if the child ignored it. The SIGKILL of
+    /// another process, e.g. the OOM killer, comes before the CPU time reached the limit.
+    pub(super) fn killed_by_cpu_limit(status: ExitStatus, cpu_time: Duration, limit: Duration) -> bool {
+        match status.signal() {
+            Some(libc::SIGXCPU) => true,
+            Some(libc::SIGKILL) => cpu_time >= limit,
+            _ => false,
+        }
+    }
+
+    /// User and system time of the children of this process which were waited for.
+    pub(super) fn children_cpu_time() -> Duration {
+        let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+        if unsafe { libc::getrusage(libc::RUSAGE_CHILDREN, &mut usage) } != 0 {
+            return Duration::ZERO;
+        }
+
+        let time = |t: libc::timeval| Duration::new(t.tv_sec as u64, t.tv_usec as u32 * 1000);
+        time(usage.ru_utime) + time(usage.ru_stime)
+    }
+}
+
+#[cfg(not(unix))]
+mod platform {
+    use super::Limits;
+    use std::process::{Command, ExitStatus};
+    use std::time::Duration;
+
+    pub(super) fn set_limits(_command: &mut Command, _limits: &Limits) {}
+
+    pub(super) fn killed_by_cpu_limit(_status: ExitStatus, _cpu_time: Duration, _limit: Duration) -> bool {
+        false
+    }
+
+    pub(super) fn children_cpu_time() -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_round_trip() {
+        let elapsed = Duration::from_nanos(1234);
+        let outcomes = [Outcome::Solved(Answer::from("007")), Outcome::Solved(Answer::Text("#.\n.#".to_string())),
+                        Outcome::Solved(Answer::from(41)), Outcome::Failed("line 1: bad\n  |".to_string()),
+                        Outcome::Panicked("index out of bounds".to_string())];
+
+        for outcome in outcomes {
+            let (read, read_elapsed) = parse_result(&result_text(&outcome, elapsed)).unwrap();
+            assert_eq!((&read, read_elapsed), (&outcome, elapsed));
+            if let (Outcome::Solved(read), Outcome::Solved(answer)) = (&read, &outcome) {
+                assert_eq!(read.kind(), answer.kind());
+            }
+        }
+
+        assert_eq!(parse_result(&result_text(&Outcome::TimedOut(elapsed), elapsed)),
+                   Some((Outcome::Failed("timed out after 1.234µs".to_string()), elapsed)));
+        assert_eq!(parse_result("solved 12\n41"), None);
+        assert_eq!(parse_result("solved x u64\n41"), None);
+    }
+
+    #[test]
+    fn keeps_the_end_of_the_logs() {
+        let logs = format!("{}\n{ALLOCATION_FAILED} 1048576 bytes failed\n", "x".repeat(3 * STDERR_TAIL));
+        let tail = forward_logs(logs.as_bytes(), std::io::sink());
+
+        assert_eq!(tail.len(), STDERR_TAIL);
+        assert!(logs.ends_with(&tail));
+        assert!(tail.contains(ALLOCATION_FAILED));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn classifies_crashes() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let limits = Limits { wall: None, cpu: Some(Duration::from_secs(2)), memory: Some(1 << 20) };
+        let signal = |signal| ExitStatus::from_raw(signal);
+        let outcome = |status, stderr, cpu| crash_outcome(status, stderr, Duration::from_millis(cpu), &limits);
+
+        assert_eq!(outcome(signal(libc::SIGABRT), "memory allocation of 64 bytes failed", 10), Outcome::ExceededMemory(1 << 20));
+        assert!(matches!(outcome(signal(libc::SIGABRT), "", 10), Outcome::Failed(_)));
+        assert!(matches!(outcome(signal(libc::SIGSEGV), "", 10), Outcome::Failed(_)));
+
+        assert_eq!(outcome(signal(libc::SIGXCPU), "", 2000), Outcome::TimedOut(Duration::from_secs(2)));
+        assert_eq!(outcome(signal(libc::SIGKILL), "", 3000), Outcome::TimedOut(Duration::from_secs(2)));
+        assert!(matches!(outcome(signal(libc::SIGKILL), "", 500), Outcome::Failed(_)));
+
+        /* Without limits, nothing is blamed on them */
+        let unlimited = Limits::default();
+        assert!(matches!(crash_outcome(signal(libc::SIGXCPU), ALLOCATION_FAILED, Duration::ZERO, &unlimited), Outcome::Failed(_)));
+    }
+}
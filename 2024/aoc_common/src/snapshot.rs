@@ -0,0 +1,153 @@
+/* Snapshots of rendered states, such as the grid of a frame, compared to the `.snap` files committed
+ * with the code, so that a change of the rendered worlds shows up as a diff to review.
+ *
+ * A missing snapshot is written on the first check. A snapshot which differs is only replaced in
+ * update mode, otherwise the check reports the lines which changed.
+ */
+use crate::animation::Frame;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// Extension of the snapshot files.
+pub const EXTENSION: &str = "snap";
+
+/// Variable which turns on the update mode of `assert_snapshot`.
+pub const UPDATE_VARIABLE: &str = "AOC_UPDATE_SNAPSHOTS";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Status {
+    Matched,
+    Created,
+    Updated,
+    /// The state differs from the snapshot, with the diff of their lines
+    Mismatched(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Matched => write!(f, "matched"),
+            Status::Created => write!(f, "created"),
+            Status::Updated => write!(f, "updated"),
+            Status::Mismatched(_) => write!(f, "MISMATCH"),
+        }
+    }
+}
+
+/// Text of a frame: its caption, if any, then its grid.
+pub fn render_frame(frame: &Frame) -> String {
+    match frame.caption.is_empty() {
+        true => frame.grid.to_string(),
+        false => format!("{}\n{}", frame.caption, frame.grid),
+    }
+}
+
+/// Compares `actual` with the snapshot at `path`, which is written when missing, or replaced when
+/// `update` is set.
+pub fn check(path: &Path, actual: &str, update: bool) -> Result<Status, String> {
+    let write = |status| {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Could not create '{}': {e}", dir.display()))?;
+        }
+        fs::write(path, actual).map_err(|e| format!("Could not write '{}': {e}", path.display()))?;
+        Ok(status)
+    };
+
+    match fs::read_to_string(path) {
+        Ok(expected) if expected == actual => Ok(Status::Matched),
+        Ok(_) if update => write(Status::Updated),
+        Ok(expected) => Ok(Status::Mismatched(diff(&expected, actual))),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => write(Status::Created),
+        Err(e) => Err(format!("Could not read '{}': {e}", path.display())),
+    }
+}
+
+/// Checks a state against its snapshot, e.g. a `Grid<char>` or a string, and panics on a mismatch.
+/// The snapshot is replaced instead when the variable `AOC_UPDATE_SNAPSHOTS` is set.
+pub fn assert_snapshot(path: impl AsRef<Path>, state: &impl fmt::Display) {
+    let path = path.as_ref();
+    let update = std::env::var_os(UPDATE_VARIABLE).is_some();
+
+    match check(path, &state.to_string(), update) {
+        Ok(Status::Mismatched(diff)) => {
+            panic!("The state differs from the snapshot '{}', set {UPDATE_VARIABLE} to update it:\n{diff}", path.display())
+        },
+        Ok(_) => {},
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Lines of `expected` and `actual` from their longest common subsequence: the removed lines start
+/// with `-`, the added ones with `+`, the common ones with a space.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    /* Length of the common subsequence of the ends of both texts */
+    let mut common = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = match old[i] == new[j] {
+                true => common[i + 1][j + 1] + 1,
+                false => common[i + 1][j].max(common[i][j + 1]),
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        }
+        else if j == new.len() || (i < old.len() && common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("- {}", old[i]));
+            i += 1;
+        }
+        else {
+            lines.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_of_lines() {
+        assert_eq!(diff("a\nb\nc", "a\nb\nc"), "  a\n  b\n  c");
+        assert_eq!(diff("a\nb\nc", "a\nx\nc"), "  a\n- b\n+ x\n  c");
+        assert_eq!(diff("a\nc", "a\nb\nc\nd"), "  a\n+ b\n  c\n+ d");
+        assert_eq!(diff("a\nb\nc\nd", "b\nd"), "- a\n  b\n- c\n  d");
+        assert_eq!(diff("", "a\nb"), "+ a\n+ b");
+        assert_eq!(diff("a\nb", ""), "- a\n- b");
+    }
+
+    #[test]
+    fn diff_keeps_the_longest_common_lines() {
+        /* Moving a line keeps the others in common */
+        assert_eq!(diff("a\nb\nc\nd", "b\nc\nd\na"), "- a\n  b\n  c\n  d\n+ a");
+        assert_eq!(diff("#..\n.#.\n..#", "#..\n...\n..#\n"), "  #..\n- .#.\n+ ...\n  ..#");
+    }
+
+    #[test]
+    fn checks_and_updates() {
+        let path = std::env::temp_dir().join(format!("aoc_snapshot_{}", std::process::id())).join("world.snap");
+
+        assert_eq!(check(&path, "#.\n.#\n", false), Ok(Status::Created));
+        assert_eq!(check(&path, "#.\n.#\n", false), Ok(Status::Matched));
+        assert_eq!(check(&path, "#.\n##\n", false), Ok(Status::Mismatched("  #.\n- .#\n+ ##".to_string())));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "#.\n.#\n");
+        assert_eq!(check(&path, "#.\n##\n", true), Ok(Status::Updated));
+        assert_eq!(check(&path, "#.\n##\n", false), Ok(Status::Matched));
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
@@ -0,0 +1,56 @@
+/* ADVENT OF CODE
+ * WebAssembly bindings of the solutions, used by the playground of `www/`.
+ *
+ * Build:
+ *   cargo build -p aoc_wasm --release --target wasm32-unknown-unknown
+ *   wasm-bindgen --target web --out-dir aoc_wasm/www/pkg target/wasm32-unknown-unknown/release/aoc_wasm.wasm
+ *
+ * Then serve `aoc_wasm/www` (e.g. `python3 -m http.server -d aoc_wasm/www`) and open it, or run
+ * the solutions without browser with `node aoc_wasm/www/headless.mjs <year> <day> <part> <file>`.
+ */
+use aoc::registry::{self, Solution};
+use aoc_common::image;
+use aoc_common::runner::{self, Outcome};
+use wasm_bindgen::prelude::*;
+
+fn find(year: u16, day: u8, part: u8) -> Result<&'static Solution, JsError> {
+    registry::find(year, day, part).ok_or(JsError::new(&format!("No solution found for year {year}, day {day}, part {part}")))
+}
+
+/// Solutions available, written `year/day/part`.
+#[wasm_bindgen]
+pub fn solutions() -> Vec<String> {
+    registry::SOLUTIONS.iter().map(|s| format!("{}/{}/{}", s.year, s.day, s.part)).collect()
+}
+
+/// Solves a part with the given input and returns its answer.
+#[wasm_bindgen]
+pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    let solution = find(year, day, part)?;
+
+    match runner::run_inline(solution.solve, input, false).0 {
+        Outcome::Solved(answer) => Ok(answer.to_string()),
+        outcome => Err(JsError::new(&outcome.to_string())),
+    }
+}
+
+/// Solves a part and returns its animation as an animated GIF, empty when the solution does not
+/// record any animation.
+#[wasm_bindgen]
+pub fn render_gif(year: u16, day: u8, part: u8, input: &str, fps: f64) -> Result<Vec<u8>, JsError> {
+    let solution = find(year, day, part)?;
+
+    let (outcome, frames) = runner::run_inline(solution.solve, input, true);
+    if let Outcome::Failed(_) | Outcome::Panicked(_) = outcome {
+        return Err(JsError::new(&outcome.to_string()));
+    }
+    if frames.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let images: Vec<image::Image> = frames.iter().map(|f| image::frame_image(f, image::DEFAULT_SCALE)).collect();
+    let mut gif = Vec::new();
+    image::encode_gif(&mut gif, &images, fps).map_err(|e| JsError::new(&e))?;
+
+    Ok(gif)
+}
@@ -0,0 +1,29 @@
+/* ADVENT OF CODE
+ * Runs a solution compiled to WebAssembly without browser, to test the playground locally.
+ *
+ * Usage: node headless.mjs <year> <day> <part> <input file> [expected answer]
+ */
+import { readFileSync } from "node:fs";
+import { initSync, solve } from "./pkg/aoc_wasm.js";
+
+const [year, day, part, file, expected] = process.argv.slice(2);
+if (!file) {
+    console.log("Usage: node headless.mjs <year> <day> <part> <input file> [expected answer]");
+    process.exit(1);
+}
+
+initSync({ module: readFileSync(new URL("./pkg/aoc_wasm_bg.wasm", import.meta.url)) });
+
+try {
+    const answer = solve(Number(year), Number(day), Number(part), readFileSync(file, "utf8"));
+    console.log(`${year} day ${day.padStart(2, "0")} part ${part}: ${answer}`);
+
+    if (expected !== undefined && answer !== expected) {
+        console.log(`Expected ${expected}`);
+        process.exit(1);
+    }
+}
+catch (e) {
+    console.log(e.message ?? String(e));
+    process.exit(1);
+}
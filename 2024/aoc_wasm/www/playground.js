@@ -0,0 +1,64 @@
+/* ADVENT OF CODE
+ * Playground: solves the pasted input with the solutions compiled to WebAssembly.
+ */
+import init, { solutions, solve, render_gif } from "./pkg/aoc_wasm.js";
+
+const FPS = 10;
+
+const select = document.getElementById("solution");
+const input = document.getElementById("input");
+const answer = document.getElementById("answer");
+const error = document.getElementById("error");
+const animation = document.getElementById("animation");
+
+function selectedSolution() {
+    const [year, day, part] = select.value.split("/").map(Number);
+    return { year, day, part };
+}
+
+function showResult(run) {
+    answer.textContent = "";
+    error.textContent = "";
+    animation.removeAttribute("src");
+
+    try {
+        run();
+    }
+    catch (e) {
+        error.textContent = e.message ?? String(e);
+    }
+}
+
+await init();
+
+for (const solution of solutions()) {
+    const [year, day, part] = solution.split("/");
+    const option = document.createElement("option");
+    option.value = solution;
+    option.textContent = `${year} day ${day.padStart(2, "0")} part ${part}`;
+    select.appendChild(option);
+}
+
+document.getElementById("file").addEventListener("change", async (event) => {
+    const file = event.target.files[0];
+    if (file) {
+        input.value = await file.text();
+    }
+});
+
+document.getElementById("solve").addEventListener("click", () => showResult(() => {
+    const { year, day, part } = selectedSolution();
+    answer.textContent = solve(year, day, part, input.value);
+}));
+
+document.getElementById("render").addEventListener("click", () => showResult(() => {
+    const { year, day, part } = selectedSolution();
+    answer.textContent = solve(year, day, part, input.value);
+
+    const gif = render_gif(year, day, part, input.value, FPS);
+    if (gif.length === 0) {
+        error.textContent = "No animation recorded by this solution";
+        return;
+    }
+    animation.src = URL.createObjectURL(new Blob([gif], { type: "image/gif" }));
+}));